@@ -0,0 +1,157 @@
+const NUM_BYTES: usize = 256;
+const ROOT: usize = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Numeral,
+    Word,
+}
+
+// a digit found in a line, start and end are byte offsets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub digit: u8,
+    pub kind: TokenKind,
+}
+
+#[derive(Clone, Debug)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+}
+
+impl Vocabulary {
+    pub fn create(words: &[(&str, u8)]) -> Vocabulary {
+        for (word, digit) in words {
+            assert!(!word.is_empty(), "words should not be empty");
+            assert!(*digit < 10, "{word} should stand for a single digit");
+        }
+        Vocabulary {
+            words: words
+                .iter()
+                .map(|(word, digit)| (word.to_string(), *digit))
+                .collect(),
+        }
+    }
+
+    pub fn create_empty() -> Vocabulary {
+        Vocabulary::create(&[])
+    }
+
+    pub fn english() -> Vocabulary {
+        Vocabulary::create(&[
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+        ])
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    next: [usize; NUM_BYTES], // full transition table, failures already resolved
+    matches: Vec<(usize, u8)>, // (word length, digit) of every word ending here
+}
+
+impl Node {
+    fn create_empty() -> Node {
+        Node {
+            next: [ROOT; NUM_BYTES],
+            matches: vec![],
+        }
+    }
+}
+
+// aho corasick automaton over the utf-8 bytes of all words,
+// reports every occurrence, overlapping ones included
+#[derive(Clone, Debug)]
+pub struct Extractor {
+    nodes: Vec<Node>,
+}
+
+impl Extractor {
+    pub fn create(vocabulary: &Vocabulary) -> Extractor {
+        let mut nodes = vec![Node::create_empty()];
+        let mut has_child = vec![[false; NUM_BYTES]];
+
+        for (word, digit) in vocabulary.words.iter() {
+            let mut current = ROOT;
+            for byte in word.bytes() {
+                if !has_child[current][byte as usize] {
+                    nodes.push(Node::create_empty());
+                    has_child.push([false; NUM_BYTES]);
+                    nodes[current].next[byte as usize] = nodes.len() - 1;
+                    has_child[current][byte as usize] = true;
+                }
+                current = nodes[current].next[byte as usize];
+            }
+            nodes[current].matches.push((word.len(), *digit));
+        }
+
+        // breadth first, so the failure of every node is finished before its children
+        let mut failures = vec![ROOT; nodes.len()];
+        let mut queue = std::collections::VecDeque::from([ROOT]);
+        while let Some(node) = queue.pop_front() {
+            for (byte, is_child) in has_child[node].iter().enumerate() {
+                let failure_next = nodes[failures[node]].next[byte];
+                if !is_child {
+                    nodes[node].next[byte] = failure_next;
+                    continue;
+                }
+
+                let child = nodes[node].next[byte];
+                failures[child] = if node == ROOT { ROOT } else { failure_next };
+                let inherited = nodes[failures[child]].matches.clone();
+                nodes[child].matches.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Extractor { nodes }
+    }
+
+    // every numeral and word in the line, ordered by start
+    pub fn find_tokens(&self, line: &str) -> Vec<Token> {
+        let mut all_tokens = vec![];
+        let mut current = ROOT;
+
+        for (position, byte) in line.bytes().enumerate() {
+            if byte.is_ascii_digit() {
+                all_tokens.push(Token {
+                    start: position,
+                    end: position + 1,
+                    digit: byte - b'0',
+                    kind: TokenKind::Numeral,
+                });
+            }
+
+            current = self.nodes[current].next[byte as usize];
+            for (word_length, digit) in self.nodes[current].matches.iter() {
+                all_tokens.push(Token {
+                    start: position + 1 - word_length,
+                    end: position + 1,
+                    digit: *digit,
+                    kind: TokenKind::Word,
+                });
+            }
+        }
+
+        all_tokens.sort_by_key(|token| (token.start, token.end));
+        all_tokens
+    }
+
+    // first and last token, which may be the same one
+    pub fn get_first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        let all_tokens = self.find_tokens(line);
+        let first = *all_tokens.first()?;
+        let last = *all_tokens.last()?;
+        Some((first, last))
+    }
+}
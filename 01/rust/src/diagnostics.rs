@@ -0,0 +1,98 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use crate::calibration::{Extractor, Token, TokenKind};
+
+const CSV_HEADER: &str = "line,value,first_digit,first_kind,first_start,first_end,last_digit,last_kind,last_start,last_end,error";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalibrationError {
+    pub line_index: usize,
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} contains no digit", self.line_index)
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineReport {
+    pub line_index: usize,
+    pub first: Token,
+    pub last: Token,
+}
+
+impl LineReport {
+    pub fn get_value(&self) -> u32 {
+        self.first.digit as u32 * 10 + self.last.digit as u32
+    }
+}
+
+fn get_kind_name(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Numeral => "numeral",
+        TokenKind::Word => "word",
+    }
+}
+
+pub fn diagnose_line(
+    extractor: &Extractor,
+    line_index: usize,
+    line: &str,
+) -> Result<LineReport, CalibrationError> {
+    let (first, last) = extractor
+        .get_first_and_last(line)
+        .ok_or(CalibrationError { line_index })?;
+    Ok(LineReport {
+        line_index,
+        first,
+        last,
+    })
+}
+
+pub fn diagnose(extractor: &Extractor, input: &str) -> Vec<Result<LineReport, CalibrationError>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| diagnose_line(extractor, line_index, line))
+        .collect()
+}
+
+// one row per line, so the reports of both parts can be diffed line by line
+pub fn export_csv(
+    all_reports: &[Result<LineReport, CalibrationError>],
+    file_path: &str,
+) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(file_path)?);
+    writeln!(writer, "{CSV_HEADER}")?;
+
+    for report in all_reports {
+        match report {
+            Ok(report) => {
+                let (first, last) = (report.first, report.last);
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{},{},{},{},",
+                    report.line_index,
+                    report.get_value(),
+                    first.digit,
+                    get_kind_name(first.kind),
+                    first.start,
+                    first.end,
+                    last.digit,
+                    get_kind_name(last.kind),
+                    last.start,
+                    last.end
+                )?;
+            }
+            Err(error) => writeln!(writer, "{},,,,,,,,,,no digit", error.line_index)?,
+        }
+    }
+    writer.flush()
+}
@@ -1,4 +1,12 @@
-use std::{collections::HashMap, fs};
+mod calibration;
+mod diagnostics;
+
+use calibration::{Extractor, Vocabulary};
+use diagnostics::CalibrationError;
+use std::fs;
+
+const CSV_PART_1_FILE_PATH: &str = "calibration_part_1.csv";
+const CSV_PART_2_FILE_PATH: &str = "calibration_part_2.csv";
 
 fn read_text() -> String {
     let file_path = "..\\Data\\input_1.txt";
@@ -6,104 +14,82 @@ fn read_text() -> String {
     return contents;
 }
 
-fn extract_number_simple(line: &String) -> i32 {
-    let mut all_numbers = String::new();
-
-    for c in line.chars() {
-        if c.is_numeric() {
-            all_numbers.push(c);
-        }
-    }
-    let mut start_and_end_numbers = String::new();
-    start_and_end_numbers.push(all_numbers.chars().nth(0).unwrap());
-    start_and_end_numbers.push(all_numbers.chars().nth(all_numbers.len() - 1).unwrap());
-
-    return start_and_end_numbers.parse::<i32>().unwrap();
-}
-
-fn get_valid_numbers() -> HashMap<&'static str, char> {
-    let valid_numbers = HashMap::from([
-        ("one", '1'),
-        ("two", '2'),
-        ("three", '3'),
-        ("four", '4'),
-        ("five", '5'),
-        ("six", '6'),
-        ("seven", '7'),
-        ("eight", '8'),
-        ("nine", '9'),
-    ]);
-    return valid_numbers;
+fn extract_number(
+    line_index: usize,
+    line: &str,
+    extractor: &Extractor,
+) -> Result<i32, CalibrationError> {
+    let report = diagnostics::diagnose_line(extractor, line_index, line)?;
+    Ok(report.get_value() as i32)
 }
 
-fn get_substring<'a>(line: &'a String, i: usize, word: &'a str) -> &'a str {
-    let substring = &line[(i)..std::cmp::min(line.len(), i + word.len())];
-    return substring;
-}
-
-fn extract_number_extra(line: &String) -> i32 {
-    let valid_numbers = get_valid_numbers();
-
-    let mut all_numbers = String::new();
-
-    for i in 0..line.len() {
-        let c = line.chars().nth(i).unwrap();
-        if c.is_numeric() {
-            all_numbers.push(c);
-            continue;
-        }
-
-        for (word, value) in &valid_numbers {
-            let substring = get_substring(line, i, word);
+fn part_one() -> Result<i32, CalibrationError> {
+    let input = read_text();
+    let extractor = Extractor::create(&Vocabulary::create_empty());
 
-            let is_number = word.eq(&substring);
-            if is_number {
-                all_numbers.push(*value);
-                continue;
-            }
-        }
+    let mut sum = 0;
+    for (line_index, line) in input.lines().enumerate() {
+        sum += extract_number(line_index, line, &extractor)?;
     }
-
-    let mut start_and_end_numbers = String::new();
-    start_and_end_numbers.push(all_numbers.chars().nth(0).unwrap());
-    start_and_end_numbers.push(all_numbers.chars().nth(all_numbers.len() - 1).unwrap());
-
-    return start_and_end_numbers.parse::<i32>().unwrap();
+    Ok(sum)
 }
 
-fn part_one() {
+fn part_two() -> Result<i32, CalibrationError> {
     let input = read_text();
+    let extractor = Extractor::create(&Vocabulary::english());
 
-    let mut _sum = 0;
-    for line in input.lines() {
-        _sum += extract_number_simple(&line.to_string());
+    let mut sum = 0;
+    for (line_index, line) in input.lines().enumerate() {
+        sum += extract_number(line_index, line, &extractor)?;
     }
+    Ok(sum)
 }
 
-fn part_two() {
+fn export_diagnostics() {
     let input = read_text();
 
-    let mut _sum = 0;
-    for line in input.lines() {
-        _sum += extract_number_extra(&line.to_string());
-    }
+    let reports_part_1 =
+        diagnostics::diagnose(&Extractor::create(&Vocabulary::create_empty()), &input);
+    let reports_part_2 = diagnostics::diagnose(&Extractor::create(&Vocabulary::english()), &input);
+    diagnostics::export_csv(&reports_part_1, CSV_PART_1_FILE_PATH).expect("csv should be writable");
+    diagnostics::export_csv(&reports_part_2, CSV_PART_2_FILE_PATH).expect("csv should be writable");
+
+    let num_different = reports_part_1
+        .iter()
+        .zip(reports_part_2.iter())
+        .filter(|(report_1, report_2)| report_1 != report_2)
+        .count();
+    println!(
+        "{num_different} lines differ between {CSV_PART_1_FILE_PATH} and {CSV_PART_2_FILE_PATH}"
+    );
 }
 
 fn main() {
-    part_one();
+    match part_one() {
+        Ok(sum) => println!("sum part 1 = {sum}"),
+        Err(error) => println!("invalid calibration document: {error}"),
+    }
+    match part_two() {
+        Ok(sum) => println!("sum part 2 = {sum}"),
+        Err(error) => println!("invalid calibration document: {error}"),
+    }
+    // `--csv` writes the tokens found on every line for both parts
+    if std::env::args().any(|arg| arg == "--csv") {
+        export_diagnostics();
+    }
 
     use std::time::Instant;
     let number_of_runs = 100;
     let mut now = Instant::now();
     for _ in 0..number_of_runs {
-        part_one();
+        let _ = part_one();
     }
     let elapsed = now.elapsed() / number_of_runs;
     println!("PART:1 ----- \nElapsed: {:.2?}", elapsed);
 
     now = Instant::now();
     for _ in 0..number_of_runs {
-        part_two();
+        let _ = part_two();
     }
 
     let elapsed = now.elapsed() / number_of_runs;
@@ -0,0 +1,197 @@
+// colors get an index the first time they are seen, so games are not limited to red, green and blue
+#[derive(Clone, Debug, Default)]
+pub struct Palette {
+    colors: Vec<String>,
+}
+
+impl Palette {
+    pub fn create(colors: &[&str]) -> Palette {
+        let mut palette = Palette::default();
+        for color in colors {
+            palette.declare(color);
+        }
+        palette
+    }
+
+    pub fn declare(&mut self, color: &str) -> usize {
+        match self.get_index(color) {
+            Some(index) => index,
+            None => {
+                self.colors.push(color.to_string());
+                self.colors.len() - 1
+            }
+        }
+    }
+
+    pub fn get_index(&self, color: &str) -> Option<usize> {
+        self.colors.iter().position(|c| c == color)
+    }
+
+    pub fn get_colors(&self) -> &[String] {
+        &self.colors
+    }
+
+    // `12 red, 13 green, 14 blue`, declaring colors that are new
+    pub fn create_cube_set(&mut self, counts: &[(&str, usize)]) -> CubeSet {
+        let mut cube_set = CubeSet::default();
+        for (color, count) in counts {
+            let index = self.declare(color);
+            cube_set.set(index, *count);
+        }
+        cube_set
+    }
+
+    pub fn describe(&self, cube_set: &CubeSet) -> String {
+        self.colors
+            .iter()
+            .enumerate()
+            .map(|(index, color)| format!("{} {color}", cube_set.get(index)))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    // product of the counts of every declared color
+    pub fn get_power(&self, cube_set: &CubeSet) -> usize {
+        (0..self.colors.len())
+            .map(|index| cube_set.get(index))
+            .product()
+    }
+}
+
+// cube counts indexed by palette color, colors past the end count as zero
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    counts: Vec<usize>,
+}
+
+impl CubeSet {
+    pub fn get(&self, color_index: usize) -> usize {
+        self.counts.get(color_index).copied().unwrap_or(0)
+    }
+
+    fn set(&mut self, color_index: usize, count: usize) {
+        if self.counts.len() <= color_index {
+            self.counts.resize(color_index + 1, 0);
+        }
+        self.counts[color_index] = count;
+    }
+
+    pub fn fits_in(&self, bag: &CubeSet) -> bool {
+        self.counts
+            .iter()
+            .enumerate()
+            .all(|(index, count)| *count <= bag.get(index))
+    }
+
+    pub fn get_union(&self, other: &CubeSet) -> CubeSet {
+        let mut union = CubeSet::default();
+        for index in 0..self.counts.len().max(other.counts.len()) {
+            union.set(index, self.get(index).max(other.get(index)));
+        }
+        union
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Game {
+    pub id: usize,
+    draws: Vec<CubeSet>,
+}
+
+impl Game {
+    // `Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green`
+    pub fn create(line: &str, palette: &mut Palette) -> Game {
+        let (left, right) = line.split_once(": ").unwrap();
+
+        let draws = right
+            .split("; ")
+            .map(|draw| {
+                let counts: Vec<(&str, usize)> = draw
+                    .split(", ")
+                    .map(|cubes| {
+                        let (number, color) = cubes.split_once(' ').unwrap();
+                        (color, number.parse::<usize>().unwrap())
+                    })
+                    .collect();
+                palette.create_cube_set(&counts)
+            })
+            .collect();
+
+        Game {
+            id: left.trim_start_matches("Game ").parse::<usize>().unwrap(),
+            draws,
+        }
+    }
+
+    pub fn get_draws(&self) -> &[CubeSet] {
+        &self.draws
+    }
+
+    pub fn is_possible(&self, bag: &CubeSet) -> bool {
+        self.draws.iter().all(|draw| draw.fits_in(bag))
+    }
+
+    // fewest cubes of every color that make the game possible
+    pub fn get_minimum_bag(&self) -> CubeSet {
+        self.draws
+            .iter()
+            .fold(CubeSet::default(), |bag, draw| bag.get_union(draw))
+    }
+}
+
+pub fn parse_input(input: &str, palette: &mut Palette) -> Vec<Game> {
+    input
+        .lines()
+        .map(|line| Game::create(line, palette))
+        .collect()
+}
+
+pub fn get_power_sum(all_games: &[Game], palette: &Palette) -> usize {
+    all_games
+        .iter()
+        .map(|game| palette.get_power(&game.get_minimum_bag()))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn input_power_sum() {
+        let input = fs::read_to_string("../Data/input_1.txt")
+            .expect("Should have been able to read the file");
+        let mut palette = Palette::create(&["red", "green", "blue"]);
+        let all_games = parse_input(&input, &mut palette);
+
+        assert_eq!(get_power_sum(&all_games, &palette), 69110);
+    }
+
+    #[test]
+    fn fourth_color() {
+        let mut palette = Palette::create(&["red", "green", "blue"]);
+        assert_eq!(palette.declare("purple"), 3);
+        assert_eq!(palette.declare("purple"), 3);
+
+        let input = "Game 1: 3 blue, 4 red, 2 purple; 1 red, 2 green\n\
+                     Game 2: 1 blue, 1 red; 2 green, 5 purple";
+        let all_games = parse_input(input, &mut palette);
+        assert_eq!(palette.get_colors().len(), 4);
+
+        let bag = palette.create_cube_set(&[("red", 12), ("green", 13), ("blue", 14)]);
+        assert!(all_games.iter().all(|game| !game.is_possible(&bag)));
+
+        let bag =
+            palette.create_cube_set(&[("red", 12), ("green", 13), ("blue", 14), ("purple", 3)]);
+        assert!(all_games[0].is_possible(&bag));
+        assert!(!all_games[1].is_possible(&bag));
+
+        assert_eq!(
+            palette.describe(&all_games[1].get_minimum_bag()),
+            "1 red, 2 green, 1 blue, 5 purple"
+        );
+        // 4 * 2 * 3 * 2 + 1 * 2 * 1 * 5
+        assert_eq!(get_power_sum(&all_games, &palette), 58);
+    }
+}
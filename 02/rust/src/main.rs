@@ -0,0 +1,8 @@
+mod cubes;
+mod part_1;
+mod part_2;
+
+fn main() {
+    part_1::main();
+    part_2::main();
+}
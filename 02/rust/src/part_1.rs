@@ -0,0 +1,61 @@
+use std::fs;
+
+use crate::cubes::{self, CubeSet, Game, Palette};
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+fn get_possible_id_sum(all_games: &[Game], bag: &CubeSet) -> usize {
+    all_games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn main() {
+    println!("PART 1 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let mut palette = Palette::create(&["red", "green", "blue"]);
+    let all_games = cubes::parse_input(&input, &mut palette);
+    let num_draws: usize = all_games.iter().map(|game| game.get_draws().len()).sum();
+    println!(
+        "{} games with {num_draws} draws over colors {:?}",
+        all_games.len(),
+        palette.get_colors()
+    );
+
+    let bag = palette.create_cube_set(&[("red", 12), ("green", 13), ("blue", 14)]);
+
+    let sum = get_possible_id_sum(&all_games, &bag);
+    println!("sum = {sum}");
+
+    let smallest_bag_for_all = all_games.iter().fold(CubeSet::default(), |bag, game| {
+        bag.get_union(&game.get_minimum_bag())
+    });
+    println!(
+        "every game is possible with {}",
+        palette.describe(&smallest_bag_for_all)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_file_sum(file_path: &str) -> usize {
+        let input = read_text(file_path.to_string());
+        let mut palette = Palette::create(&["red", "green", "blue"]);
+        let all_games = cubes::parse_input(&input, &mut palette);
+        let bag = palette.create_cube_set(&[("red", 12), ("green", 13), ("blue", 14)]);
+        get_possible_id_sum(&all_games, &bag)
+    }
+
+    #[test]
+    fn possible_id_sum() {
+        assert_eq!(get_file_sum("../Data/test_input_1.txt"), 8);
+        assert_eq!(get_file_sum("../Data/input_1.txt"), 2810);
+    }
+}
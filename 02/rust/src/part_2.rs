@@ -0,0 +1,32 @@
+use std::fs;
+
+use crate::cubes::{self, Palette};
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+pub fn main() {
+    println!("PART 2 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let mut palette = Palette::create(&["red", "green", "blue"]);
+    let all_games = cubes::parse_input(&input, &mut palette);
+
+    let sum = cubes::get_power_sum(&all_games, &palette);
+    println!("sum = {sum}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_sum() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+        let mut palette = Palette::create(&["red", "green", "blue"]);
+        let all_games = cubes::parse_input(&input, &mut palette);
+
+        assert_eq!(cubes::get_power_sum(&all_games, &palette), 2286);
+    }
+}
@@ -0,0 +1,42 @@
+use std::fs;
+
+use crate::schematic::Schematic;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+fn get_part_number_sum(schematic: &Schematic) -> u64 {
+    schematic
+        .get_part_numbers()
+        .iter()
+        .map(|number| number.value)
+        .sum()
+}
+
+pub fn main() {
+    println!("PART 1 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let schematic = Schematic::create(&input);
+
+    let sum = get_part_number_sum(&schematic);
+    println!("sum = {sum}");
+
+    let num_shared = (0..schematic.get_numbers().len())
+        .filter(|number_index| schematic.get_adjacent_symbols(*number_index).len() > 1)
+        .count();
+    println!("numbers touching more than one symbol = {num_shared}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_sum() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+
+        assert_eq!(get_part_number_sum(&Schematic::create(&input)), 4361);
+    }
+}
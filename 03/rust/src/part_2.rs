@@ -0,0 +1,47 @@
+use std::fs;
+
+use crate::schematic::Schematic;
+
+const MAX_ADJACENT_NUMBERS: usize = 4;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+pub fn main() {
+    println!("PART 2 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let schematic = Schematic::create(&input);
+
+    let sum: u64 = schematic.get_gear_ratios().iter().sum();
+    println!("sum = {sum}");
+
+    for k in 0..=MAX_ADJACENT_NUMBERS {
+        let symbols = schematic.get_symbols_with_k_numbers(k, None);
+        let mut kinds: Vec<char> = symbols
+            .iter()
+            .map(|symbol_index| schematic.get_symbols()[*symbol_index].symbol)
+            .collect();
+        kinds.sort();
+        kinds.dedup();
+        let kinds: String = kinds.into_iter().collect();
+        println!(
+            "{} symbols with {k} adjacent numbers: {kinds}",
+            symbols.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_sum() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+        let sum: u64 = Schematic::create(&input).get_gear_ratios().iter().sum();
+
+        assert_eq!(sum, 467835);
+    }
+}
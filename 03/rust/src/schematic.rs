@@ -0,0 +1,206 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: u64,
+    pub y: usize,
+    pub x_start: usize,
+    pub x_end: usize, // last digit, included
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub y: usize,
+    pub x: usize,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit() && !c.is_whitespace()
+}
+
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    number_at: Vec<Vec<Option<usize>>>, // number index per cell, rows may differ in length
+    symbol_at: Vec<Vec<Option<usize>>>,
+}
+
+impl Schematic {
+    pub fn create(input: &str) -> Schematic {
+        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let mut schematic = Schematic {
+            numbers: vec![],
+            symbols: vec![],
+            number_at: rows.iter().map(|row| vec![None; row.len()]).collect(),
+            symbol_at: rows.iter().map(|row| vec![None; row.len()]).collect(),
+        };
+
+        for (y, row) in rows.iter().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if is_symbol(row[x]) {
+                    schematic.symbol_at[y][x] = Some(schematic.symbols.len());
+                    schematic.symbols.push(Symbol {
+                        symbol: row[x],
+                        y,
+                        x,
+                    });
+                }
+                if !row[x].is_ascii_digit() {
+                    x += 1;
+                    continue;
+                }
+
+                // the number runs until the first non digit or the end of the row
+                let x_start = x;
+                let mut value = 0;
+                while x < row.len() && row[x].is_ascii_digit() {
+                    value = value * 10 + row[x].to_digit(10).unwrap() as u64;
+                    schematic.number_at[y][x] = Some(schematic.numbers.len());
+                    x += 1;
+                }
+                schematic.numbers.push(Number {
+                    value,
+                    y,
+                    x_start,
+                    x_end: x - 1,
+                });
+            }
+        }
+        schematic
+    }
+
+    pub fn get_numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn get_symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    // cells around the rectangle from (y, x_start) to (y, x_end), clipped to the grid
+    fn get_surrounding(&self, y: usize, x_start: usize, x_end: usize) -> Vec<(usize, usize)> {
+        let mut surrounding = vec![];
+        for yy in y.saturating_sub(1)..=y + 1 {
+            let Some(row) = self.number_at.get(yy) else {
+                continue;
+            };
+            for xx in x_start.saturating_sub(1)..=x_end + 1 {
+                let is_inside = yy == y && (x_start..=x_end).contains(&xx);
+                if xx < row.len() && !is_inside {
+                    surrounding.push((yy, xx));
+                }
+            }
+        }
+        surrounding
+    }
+
+    pub fn get_adjacent_symbols(&self, number_index: usize) -> Vec<usize> {
+        let number = self.numbers[number_index];
+        self.get_surrounding(number.y, number.x_start, number.x_end)
+            .into_iter()
+            .filter_map(|(y, x)| self.symbol_at[y][x])
+            .collect()
+    }
+
+    pub fn get_adjacent_numbers(&self, symbol_index: usize) -> Vec<usize> {
+        let symbol = self.symbols[symbol_index];
+        let mut adjacent_numbers: Vec<usize> = self
+            .get_surrounding(symbol.y, symbol.x, symbol.x)
+            .into_iter()
+            .filter_map(|(y, x)| self.number_at[y][x])
+            .collect();
+        // a number touching with several digits is still one neighbour
+        adjacent_numbers.sort();
+        adjacent_numbers.dedup();
+        adjacent_numbers
+    }
+
+    pub fn get_part_numbers(&self) -> Vec<Number> {
+        (0..self.numbers.len())
+            .filter(|number_index| !self.get_adjacent_symbols(*number_index).is_empty())
+            .map(|number_index| self.numbers[number_index])
+            .collect()
+    }
+
+    // symbols with exactly k adjacent numbers, optionally only one kind of symbol
+    pub fn get_symbols_with_k_numbers(&self, k: usize, symbol: Option<char>) -> Vec<usize> {
+        (0..self.symbols.len())
+            .filter(|symbol_index| symbol.is_none_or(|c| self.symbols[*symbol_index].symbol == c))
+            .filter(|symbol_index| self.get_adjacent_numbers(*symbol_index).len() == k)
+            .collect()
+    }
+
+    // product of the two numbers around every `*` that touches exactly two
+    pub fn get_gear_ratios(&self) -> Vec<u64> {
+        self.get_symbols_with_k_numbers(2, Some('*'))
+            .into_iter()
+            .map(|symbol_index| {
+                self.get_adjacent_numbers(symbol_index)
+                    .into_iter()
+                    .map(|number_index| self.numbers[number_index].value)
+                    .product()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // numbers in the first and last row and column, and a gear between two numbers of one row
+    const EDGE_SCHEMATIC: &str = "12......34\n\
+                                  *........$\n\
+                                  ..........\n\
+                                  ......#..+\n\
+                                  5.7*91..48";
+
+    #[test]
+    fn numbers_at_the_edges() {
+        let schematic = Schematic::create(EDGE_SCHEMATIC);
+
+        let positions: Vec<(u64, usize, usize, usize)> = schematic
+            .get_numbers()
+            .iter()
+            .map(|number| (number.value, number.y, number.x_start, number.x_end))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                (12, 0, 0, 1),
+                (34, 0, 8, 9),
+                (5, 4, 0, 0),
+                (7, 4, 2, 2),
+                (91, 4, 4, 5),
+                (48, 4, 8, 9)
+            ]
+        );
+
+        let part_numbers: Vec<u64> = schematic
+            .get_part_numbers()
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(part_numbers, vec![12, 34, 7, 91, 48]);
+    }
+
+    #[test]
+    fn gear_between_numbers_of_one_row() {
+        let schematic = Schematic::create(EDGE_SCHEMATIC);
+
+        let gear_index = schematic
+            .get_symbols()
+            .iter()
+            .position(|symbol| (symbol.symbol, symbol.y, symbol.x) == ('*', 4, 3))
+            .unwrap();
+        let adjacent: Vec<u64> = schematic
+            .get_adjacent_numbers(gear_index)
+            .into_iter()
+            .map(|number_index| schematic.get_numbers()[number_index].value)
+            .collect();
+        assert_eq!(adjacent, vec![7, 91]);
+
+        assert_eq!(schematic.get_gear_ratios(), vec![7 * 91]);
+    }
+}
@@ -0,0 +1,33 @@
+use std::fs;
+
+use crate::scratchcards::{self, Card};
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+fn get_points_sum(all_cards: &[Card]) -> usize {
+    all_cards.iter().map(|card| card.get_points()).sum()
+}
+
+pub fn main() {
+    println!("PART 1 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let all_cards = scratchcards::parse_input(&input);
+
+    let sum = get_points_sum(&all_cards);
+    println!("sum = {sum}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_sum() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+
+        assert_eq!(get_points_sum(&scratchcards::parse_input(&input)), 13);
+    }
+}
@@ -0,0 +1,66 @@
+use std::fs;
+
+use crate::scratchcards::{self, Card, Cascade, CascadeNode};
+
+const CASCADE_TREE_DEPTH: usize = 2;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+fn print_cascade_tree(all_cards: &[Card], node: &CascadeNode, indent: usize) {
+    println!(
+        "{}card {} gave {} copies",
+        "  ".repeat(indent),
+        all_cards[node.card_index].id,
+        node.copies_given
+    );
+    for contributor in node.contributors.iter() {
+        print_cascade_tree(all_cards, contributor, indent + 1);
+    }
+}
+
+pub fn main() {
+    println!("PART 2 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let all_cards = scratchcards::parse_input(&input);
+    let cascade = Cascade::create(&all_cards);
+
+    let sum = cascade.get_total_instances();
+    println!("sum = {sum}");
+
+    let (most_copied, num_instances) = cascade
+        .get_number_of_instances()
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, num_instances)| **num_instances)
+        .unwrap();
+    let original_contributors: Vec<usize> = cascade
+        .get_original_contributors(most_copied)
+        .into_iter()
+        .map(|index| all_cards[index].id)
+        .collect();
+    println!(
+        "card {} has {num_instances} instances, copies came from cards {original_contributors:?}",
+        all_cards[most_copied].id
+    );
+    print_cascade_tree(
+        &all_cards,
+        &cascade.get_cascade_tree(most_copied, CASCADE_TREE_DEPTH),
+        0,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_sum() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+        let all_cards = scratchcards::parse_input(&input);
+
+        assert_eq!(Cascade::create(&all_cards).get_total_instances(), 30);
+    }
+}
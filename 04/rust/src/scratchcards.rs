@@ -0,0 +1,174 @@
+const BITS_PER_CHUNK: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Bitset {
+    chunks: Vec<u64>,
+}
+
+impl Bitset {
+    fn create(numbers: &[usize]) -> Bitset {
+        let max_number = numbers.iter().max().copied().unwrap_or(0);
+        let mut bitset = Bitset {
+            chunks: vec![0; max_number / BITS_PER_CHUNK + 1],
+        };
+        for number in numbers {
+            bitset.chunks[number / BITS_PER_CHUNK] |= 1 << (number % BITS_PER_CHUNK);
+        }
+        bitset
+    }
+
+    fn count_common(&self, other: &Bitset) -> usize {
+        self.chunks
+            .iter()
+            .zip(other.chunks.iter())
+            .map(|(chunk, other_chunk)| (chunk & other_chunk).count_ones() as usize)
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    pub id: usize,
+    winning_numbers: Bitset,
+    my_numbers: Bitset,
+}
+
+impl Card {
+    // `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`
+    pub fn create(line: &str) -> Card {
+        let (left, right) = line.split_once(" | ").unwrap();
+        let (card, left) = left.split_once(": ").unwrap();
+
+        Card {
+            id: card
+                .trim_start_matches("Card")
+                .trim()
+                .parse::<usize>()
+                .unwrap(),
+            winning_numbers: Bitset::create(&Card::clean_numbers(left)),
+            my_numbers: Bitset::create(&Card::clean_numbers(right)),
+        }
+    }
+
+    fn clean_numbers(numbers: &str) -> Vec<usize> {
+        numbers
+            .split_whitespace()
+            .map(|num| num.parse::<usize>().unwrap())
+            .collect()
+    }
+
+    pub fn get_num_matches(&self) -> usize {
+        self.my_numbers.count_common(&self.winning_numbers)
+    }
+
+    pub fn get_points(&self) -> usize {
+        match self.get_num_matches() {
+            0 => 0,
+            num_matches => 1 << (num_matches - 1),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<Card> {
+    input.lines().map(Card::create).collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct CascadeNode {
+    pub card_index: usize,
+    pub copies_given: usize, // instances this card handed to its parent in the tree
+    pub contributors: Vec<CascadeNode>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cascade {
+    num_matches: Vec<usize>,
+    number_of_instances: Vec<usize>,
+}
+
+impl Cascade {
+    // every instance of a card wins one copy of each of the following num_matches cards,
+    // so a card adds its instance count to a range, which a difference array does in one pass
+    pub fn create(all_cards: &[Card]) -> Cascade {
+        let num_cards = all_cards.len();
+        let num_matches: Vec<usize> = all_cards
+            .iter()
+            .map(|card| card.get_num_matches())
+            .collect();
+
+        let mut number_of_instances = vec![0; num_cards];
+        let mut differences = vec![0_isize; num_cards + 1];
+        let mut running_copies = 0_isize;
+        for index in 0..num_cards {
+            running_copies += differences[index];
+            number_of_instances[index] = 1 + running_copies as usize;
+
+            let last_won = (index + num_matches[index]).min(num_cards - 1);
+            if last_won > index {
+                differences[index + 1] += number_of_instances[index] as isize;
+                differences[last_won + 1] -= number_of_instances[index] as isize;
+            }
+        }
+
+        Cascade {
+            num_matches,
+            number_of_instances,
+        }
+    }
+
+    pub fn get_number_of_instances(&self) -> &[usize] {
+        &self.number_of_instances
+    }
+
+    pub fn get_total_instances(&self) -> usize {
+        self.number_of_instances.iter().sum()
+    }
+
+    // earlier cards whose wins reach card_index, with the copies each of them gave
+    pub fn get_contributors(&self, card_index: usize) -> Vec<(usize, usize)> {
+        (0..card_index)
+            .filter(|index| index + self.num_matches[*index] >= card_index)
+            .map(|index| (index, self.number_of_instances[index]))
+            .collect()
+    }
+
+    // contributors of contributors, up to max_depth levels below card_index
+    pub fn get_cascade_tree(&self, card_index: usize, max_depth: usize) -> CascadeNode {
+        self.get_cascade_node(card_index, self.number_of_instances[card_index], max_depth)
+    }
+
+    fn get_cascade_node(
+        &self,
+        card_index: usize,
+        copies_given: usize,
+        depth: usize,
+    ) -> CascadeNode {
+        let contributors = match depth {
+            0 => vec![],
+            _ => self
+                .get_contributors(card_index)
+                .into_iter()
+                .map(|(index, copies)| self.get_cascade_node(index, copies, depth - 1))
+                .collect(),
+        };
+        CascadeNode {
+            card_index,
+            copies_given,
+            contributors,
+        }
+    }
+
+    // every original card that handed copies to card_index, directly or through other cards
+    pub fn get_original_contributors(&self, card_index: usize) -> Vec<usize> {
+        let mut is_contributor = vec![false; card_index + 1];
+        is_contributor[card_index] = true;
+        for index in (0..card_index).rev() {
+            let last_won = index + self.num_matches[index];
+            is_contributor[index] =
+                (index + 1..=last_won.min(card_index)).any(|won| is_contributor[won]);
+        }
+        (0..card_index)
+            .filter(|index| is_contributor[*index])
+            .collect()
+    }
+}
@@ -0,0 +1,114 @@
+use std::fs;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // get_cleaned_input splits the almanac on `\r\n`
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+pub struct AlmanacSize {
+    pub num_seed_ranges: usize,
+    pub max_seed_range_length: u64,
+    pub ranges_per_map: usize,
+    pub max_value: u64,
+}
+
+impl AlmanacSize {
+    // roughly `input_1.txt`, but with seed ranges part 2 can brute force
+    pub fn create(scale: usize) -> AlmanacSize {
+        AlmanacSize {
+            num_seed_ranges: 10 * scale,
+            max_seed_range_length: 1_000_000,
+            ranges_per_map: 30 * scale,
+            max_value: 4_000_000_000,
+        }
+    }
+}
+
+// cuts [0, max_value) into segments and moves them to shuffled destinations,
+// some segments are left out so their numbers map to themselves
+fn generate_map(size: &AlmanacSize, rng: &mut StdRng) -> Vec<(u64, u64, u64)> {
+    let mut cuts: Vec<u64> = (1..size.ranges_per_map)
+        .map(|_| rng.gen_range(1..size.max_value))
+        .collect();
+    cuts.push(0);
+    cuts.push(size.max_value);
+    cuts.sort();
+    cuts.dedup();
+
+    let segments: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+    let mut destination_order: Vec<usize> = (0..segments.len()).collect();
+    destination_order.shuffle(rng);
+
+    let mut rows = vec![];
+    let mut destination_start = 0;
+    for index in destination_order {
+        let (source_start, length) = segments[index];
+        rows.push((destination_start, source_start, length));
+        destination_start += length;
+    }
+
+    rows.shuffle(rng);
+    let num_kept = rows.len() - rows.len() / 10;
+    rows.truncate(num_kept.max(1));
+    rows
+}
+
+pub fn generate_almanac(size: &AlmanacSize, rng: &mut StdRng) -> String {
+    let seeds: Vec<String> = (0..size.num_seed_ranges)
+        .flat_map(|_| {
+            let length = rng.gen_range(1..=size.max_seed_range_length);
+            let start = rng.gen_range(0..size.max_value - length);
+            [start.to_string(), length.to_string()]
+        })
+        .collect();
+
+    let mut blocks = vec![format!("seeds: {}", seeds.join(" "))];
+    for name in MAP_NAMES {
+        let rows: Vec<String> = generate_map(size, rng)
+            .into_iter()
+            .map(|(destination, source, length)| format!("{destination} {source} {length}"))
+            .collect();
+        blocks.push(format!(
+            "{name} map:{LINE_ENDING}{}",
+            rows.join(LINE_ENDING)
+        ));
+    }
+    blocks.join(&LINE_ENDING.repeat(2))
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random almanac instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let almanac = generate_almanac(&AlmanacSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, almanac).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    true
+}
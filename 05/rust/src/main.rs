@@ -1,5 +1,14 @@
 use std::{collections::HashMap, fs};
 
+mod generator;
+
+// `--input=PATH` reads another almanac, e.g. generated_input.txt
+fn get_input_path(default: &str) -> String {
+    std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--input=")?.to_string()))
+        .unwrap_or(default.to_string())
+}
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -155,7 +164,7 @@ impl GardenerBook {
 // }
 
 fn run_1() {
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let (seeds, maps) = get_cleaned_input(&input);
 
     let book = GardenerBook::create(maps);
@@ -201,7 +210,7 @@ fn run_2() {
     use indicatif::ProgressIterator;
     use rayon::prelude::*;
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let (seeds, maps) = get_cleaned_input(&input);
 
     let book = GardenerBook::create(maps);
@@ -226,6 +235,9 @@ fn run_2() {
 }
 
 fn main() {
+    if generator::main() {
+        return;
+    }
     // test_1();
     run_1();
     // test_2();
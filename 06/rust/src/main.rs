@@ -0,0 +1,8 @@
+mod part_1;
+mod part_2;
+mod race;
+
+fn main() {
+    part_1::main();
+    part_2::main();
+}
@@ -0,0 +1,51 @@
+use std::fs;
+
+use crate::race;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+// `Time:      7  15   30` and `Distance:  9  40  200` as (time, record_distance) per race
+fn parse_input(input: String) -> Vec<(u128, u128)> {
+    let mut lines = input.lines().map(|line| {
+        let (_, numbers) = line.split_once(':').unwrap();
+        numbers
+            .split_whitespace()
+            .map(|num| num.parse::<u128>().unwrap())
+            .collect::<Vec<u128>>()
+    });
+    let times = lines.next().unwrap();
+    let distances = lines.next().unwrap();
+
+    times.into_iter().zip(distances).collect()
+}
+
+pub fn main() {
+    println!("PART 1 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let all_races = parse_input(input);
+
+    let product: u128 = all_races
+        .into_iter()
+        .map(|(time, record_distance)| race::count_beating_times(time, record_distance))
+        .product();
+    println!("product = {product}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_product() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+        let product: u128 = parse_input(input)
+            .into_iter()
+            .map(|(time, record_distance)| race::count_beating_times(time, record_distance))
+            .product();
+
+        assert_eq!(product, 288);
+    }
+}
@@ -0,0 +1,45 @@
+use std::fs;
+
+use crate::race;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+// the spaces between the numbers are bad kerning, so each line is one number
+fn parse_input(input: String) -> (u128, u128) {
+    let mut lines = input.lines().map(|line| {
+        let (_, numbers) = line.split_once(':').unwrap();
+        numbers
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .parse::<u128>()
+            .unwrap()
+    });
+
+    (lines.next().unwrap(), lines.next().unwrap())
+}
+
+pub fn main() {
+    println!("PART 2 ------------");
+    let input = read_text("..\\Data\\input_1.txt".to_string());
+
+    let (time, record_distance) = parse_input(input);
+
+    let beating_times = race::count_beating_times(time, record_distance);
+    println!("beating_times = {beating_times}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_beating_times() {
+        let input = read_text("../Data/test_input_1.txt".to_string());
+        let (time, record_distance) = parse_input(input);
+
+        assert_eq!(race::count_beating_times(time, record_distance), 71503);
+    }
+}
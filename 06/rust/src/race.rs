@@ -0,0 +1,69 @@
+fn is_beating(time: u128, record_distance: u128, charge_time: u128) -> bool {
+    (time - charge_time) * charge_time > record_distance
+}
+
+// charge_time * (time - charge_time) > record_distance holds strictly between the roots
+// (time -+ sqrt(time^2 - 4 * record_distance)) / 2, the winners are symmetric around time / 2
+pub fn count_beating_times(time: u128, record_distance: u128) -> u128 {
+    let squared_time = time * time;
+    if squared_time <= 4 * record_distance {
+        return 0;
+    }
+    let root = (squared_time - 4 * record_distance).isqrt();
+
+    // the floored root puts the guess at most one step off the first winner
+    let mut first_winner = (time - root) / 2;
+    while first_winner > 0 && is_beating(time, record_distance, first_winner - 1) {
+        first_winner -= 1;
+    }
+    while first_winner <= time / 2 && !is_beating(time, record_distance, first_winner) {
+        first_winner += 1;
+    }
+
+    match first_winner <= time / 2 {
+        true => time + 1 - 2 * first_winner,
+        false => 0, // the best charge time only ties the record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_beating_times_brute_force(time: u128, record_distance: u128) -> u128 {
+        (0..=time)
+            .filter(|charge_time| is_beating(time, record_distance, *charge_time))
+            .count() as u128
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for time in 0..60 {
+            for record_distance in 0..=time * time / 4 + 1 {
+                assert_eq!(
+                    count_beating_times(time, record_distance),
+                    count_beating_times_brute_force(time, record_distance),
+                    "time {time}, record_distance {record_distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_square_discriminant() {
+        // 30^2 - 4 * 200 = 10^2, the roots 10 and 20 only tie the record
+        assert_eq!(count_beating_times(30, 200), 9);
+        // 10^2 - 4 * 25 = 0, the best charge time only ties the record
+        assert_eq!(count_beating_times(10, 25), 0);
+        // 7^2 - 4 * 6 = 5^2, the roots 1 and 6 only tie the record
+        assert_eq!(count_beating_times(7, 6), 4);
+    }
+
+    #[test]
+    fn part_2_sized_races() {
+        assert_eq!(count_beating_times(71530, 940200), 71503);
+        assert_eq!(count_beating_times(35696887, 213116810861248), 20537782);
+        // time^2 no longer fits into a u64
+        assert_eq!(count_beating_times(10_000_000_000, 0), 9_999_999_999);
+    }
+}
@@ -0,0 +1,88 @@
+use std::{collections::HashSet, fs};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // the hands are split on `\r\n`
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// group sizes of every hand type, from five of a kind to high card
+const HAND_SHAPES: [&[usize]; 7] = [
+    &[5],
+    &[4, 1],
+    &[3, 2],
+    &[3, 1, 1],
+    &[2, 2, 1],
+    &[2, 1, 1, 1],
+    &[1, 1, 1, 1, 1],
+];
+
+pub struct HandsSize {
+    pub num_hands: usize,
+    pub max_bid: usize,
+}
+
+impl HandsSize {
+    pub fn create(scale: usize) -> HandsSize {
+        HandsSize {
+            num_hands: 1000 * scale,
+            max_bid: 1000,
+        }
+    }
+}
+
+// the shape is drawn first, so every hand type shows up about equally often
+fn generate_hand(rng: &mut StdRng) -> String {
+    let shape = HAND_SHAPES.choose(rng).unwrap();
+    let cards: Vec<&char> = CARDS.choose_multiple(rng, shape.len()).collect();
+
+    let mut hand: Vec<char> = shape
+        .iter()
+        .zip(cards)
+        .flat_map(|(group_size, card)| vec![*card; *group_size])
+        .collect();
+    hand.shuffle(rng);
+    hand.into_iter().collect()
+}
+
+pub fn generate_hands(size: &HandsSize, rng: &mut StdRng) -> String {
+    let mut seen = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < size.num_hands {
+        let hand = generate_hand(rng);
+        if seen.insert(hand.clone()) {
+            lines.push(format!("{hand} {}", rng.gen_range(1..=size.max_bid)));
+        }
+    }
+    lines.join(LINE_ENDING)
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes random hands instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let hands = generate_hands(&HandsSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, hands).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    true
+}
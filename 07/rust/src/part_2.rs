@@ -1,5 +1,7 @@
 use std::{collections::HashMap, fs};
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -143,7 +145,7 @@ fn sort_hands(cards_to_bet: Vec<Hand>) -> Vec<Hand> {
 
 pub fn main() {
     println!("PART 2 ------------");
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
 
     let cards_to_bet = prepare_input(&input);
     let sorted_cards_to_bet = sort_hands(cards_to_bet);
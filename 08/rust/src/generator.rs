@@ -0,0 +1,190 @@
+use std::{collections::HashSet, fs};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // the node lines are split on `\r\n`
+
+const NAME_LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct NetworkSize {
+    pub instruction_length: usize,
+    pub num_ghosts: usize,
+    pub min_cycle_length: usize,
+    pub max_cycle_length: usize,
+}
+
+impl NetworkSize {
+    pub fn create(scale: usize) -> NetworkSize {
+        NetworkSize {
+            instruction_length: 280,
+            num_ghosts: 6,
+            min_cycle_length: 40 * scale,
+            max_cycle_length: 80 * scale,
+        }
+    }
+}
+
+pub struct Network {
+    pub text: String,
+    pub cycle_lengths: Vec<usize>, // first ghost walks from AAA to ZZZ
+}
+
+impl Network {
+    pub fn get_steps_part_1(&self) -> u128 {
+        self.cycle_lengths[0] as u128
+    }
+
+    pub fn get_steps_part_2(&self) -> u128 {
+        self.cycle_lengths
+            .iter()
+            .fold(1, |steps, length| num::integer::lcm(steps, *length as u128))
+    }
+}
+
+struct NameGenerator {
+    used: HashSet<String>,
+}
+
+impl NameGenerator {
+    fn create() -> NameGenerator {
+        NameGenerator {
+            used: HashSet::from(["AAA".to_string(), "ZZZ".to_string()]),
+        }
+    }
+
+    // a fresh name ending in `last`, or in anything but A and Z
+    fn get_name(&mut self, last: Option<u8>, rng: &mut StdRng) -> String {
+        loop {
+            let last = last.unwrap_or_else(|| NAME_LETTERS[rng.gen_range(1..25)]);
+            let name: String = [
+                *NAME_LETTERS.choose(rng).unwrap(),
+                *NAME_LETTERS.choose(rng).unwrap(),
+                last,
+            ]
+            .into_iter()
+            .map(char::from)
+            .collect();
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+// every ghost runs around a ring whose nodes come in pairs with the same successors, so the
+// instructions only pick which of the pair is visited and `..Z` comes up every cycle_length steps,
+// the start `..A` shares its successors with `..Z`
+fn generate_ghost(
+    start: String,
+    end: String,
+    cycle_length: usize,
+    names: &mut NameGenerator,
+    rng: &mut StdRng,
+) -> Vec<(String, String, String)> {
+    let mut ring = vec![end];
+    let mut twins = vec![None];
+    for _ in 1..cycle_length {
+        ring.push(names.get_name(None, rng));
+        twins.push(Some(names.get_name(None, rng)));
+    }
+
+    let mut nodes = vec![];
+    for index in 0..cycle_length {
+        let next = (index + 1) % cycle_length;
+        let mut successors = [ring[next].clone(), ring[next].clone()];
+        if let Some(twin) = &twins[next] {
+            successors[1] = twin.clone();
+        }
+        successors.shuffle(rng);
+        let [left, right] = successors;
+
+        nodes.push((ring[index].clone(), left.clone(), right.clone()));
+        if let Some(twin) = &twins[index] {
+            nodes.push((twin.clone(), right.clone(), left.clone()));
+        }
+        if index == 0 {
+            nodes.push((start.clone(), left, right));
+        }
+    }
+    nodes
+}
+
+pub fn generate_network(size: &NetworkSize, rng: &mut StdRng) -> Network {
+    let num_names = NAME_LETTERS.len() * NAME_LETTERS.len() * (NAME_LETTERS.len() - 2);
+    assert!(
+        2 * size.num_ghosts * size.max_cycle_length < num_names,
+        "three letter names can't cover that many nodes"
+    );
+    let instructions: String = (0..size.instruction_length)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let mut names = NameGenerator::create();
+    let mut cycle_lengths = vec![];
+    let mut nodes = vec![];
+    for ghost in 0..size.num_ghosts {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                names.get_name(Some(b'A'), rng),
+                names.get_name(Some(b'Z'), rng),
+            ),
+        };
+        let cycle_length = rng.gen_range(size.min_cycle_length..=size.max_cycle_length);
+        nodes.append(&mut generate_ghost(
+            start,
+            end,
+            cycle_length,
+            &mut names,
+            rng,
+        ));
+        cycle_lengths.push(cycle_length);
+    }
+    nodes.shuffle(rng);
+
+    let lines: Vec<String> = nodes
+        .into_iter()
+        .map(|(node, left, right)| format!("{node} = ({left}, {right})"))
+        .collect();
+    Network {
+        text: format!(
+            "{instructions}{LINE_ENDING}{LINE_ENDING}{}",
+            lines.join(LINE_ENDING)
+        ),
+        cycle_lengths,
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random network instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let network = generate_network(&NetworkSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &network.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "cycle lengths {:?}, expected steps {} and {}",
+        network.cycle_lengths,
+        network.get_steps_part_1(),
+        network.get_steps_part_2()
+    );
+    true
+}
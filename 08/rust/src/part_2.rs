@@ -1,5 +1,7 @@
 use std::{collections::HashMap, fs};
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -94,7 +96,7 @@ fn find_smallest_common(end_steps: Vec<u128>) -> u128 {
 
 pub fn main() {
     println!("PART 2 ------------");
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
 
     let (directions, network) = parse_input(input);
 
@@ -0,0 +1,103 @@
+use std::fs;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // every history is one `\r\n` separated line
+
+pub struct SequencesSize {
+    pub num_sequences: usize,
+    pub sequence_length: usize,
+    pub max_degree: usize, // below sequence_length - 1, so the differences reach zero
+    pub max_coefficient: i128,
+}
+
+impl SequencesSize {
+    pub fn create(scale: usize) -> SequencesSize {
+        SequencesSize {
+            num_sequences: 200 * scale,
+            sequence_length: 21,
+            max_degree: 15,
+            max_coefficient: 10,
+        }
+    }
+}
+
+pub struct Sequences {
+    pub text: String,
+    pub next_sum: i128,
+    pub previous_sum: i128,
+}
+
+// binomial coefficient x over k, also for negative x
+fn binomial(x: i128, k: usize) -> i128 {
+    (0..k as i128).fold(1, |value, i| value * (x - i) / (i + 1))
+}
+
+// polynomials in newton form sum(coefficient_k * (x over k)) stay integer everywhere,
+// and coefficient_k is the first value of the k-th differences
+fn evaluate(coefficients: &[i128], x: i128) -> i128 {
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(k, coefficient)| coefficient * binomial(x, k))
+        .sum()
+}
+
+pub fn generate_sequences(size: &SequencesSize, rng: &mut StdRng) -> Sequences {
+    assert!(size.max_degree + 1 < size.sequence_length);
+
+    let mut lines = vec![];
+    let mut next_sum = 0;
+    let mut previous_sum = 0;
+    for _ in 0..size.num_sequences {
+        let degree = rng.gen_range(0..=size.max_degree);
+        let coefficients: Vec<i128> = (0..=degree)
+            .map(|_| rng.gen_range(-size.max_coefficient..=size.max_coefficient))
+            .collect();
+
+        let values: Vec<String> = (0..size.sequence_length as i128)
+            .map(|x| evaluate(&coefficients, x).to_string())
+            .collect();
+        lines.push(values.join(" "));
+        next_sum += evaluate(&coefficients, size.sequence_length as i128);
+        previous_sum += evaluate(&coefficients, -1);
+    }
+
+    Sequences {
+        text: lines.join(LINE_ENDING),
+        next_sum,
+        previous_sum,
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes random sequences instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let sequences = generate_sequences(&SequencesSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &sequences.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "expected sums {} and {}",
+        sequences.next_sum, sequences.previous_sum
+    );
+    true
+}
@@ -1,5 +1,7 @@
 use std::fs;
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -34,7 +36,7 @@ fn find_sequence_ending(sequence: &Vec<i128>) -> i128 {
 
 pub fn main() {
     println!("PART 2 ------------");
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
 
     let readings = parse_input(input);
 
@@ -0,0 +1,308 @@
+use std::{cmp::Ordering, fs};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // the maze rows are split on `\r\n`
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+pub struct MazeSize {
+    pub blocks_wide: usize,
+    pub blocks_high: usize,
+    pub loop_fill: f64, // share of the 2x2 blocks the loop runs through
+    pub max_gap: usize, // stretch between neighbouring loop rows and cols, 1 keeps them touching
+    pub ground_density: f64,
+}
+
+impl MazeSize {
+    // about 140x140 tiles per scale
+    pub fn create(scale: usize) -> MazeSize {
+        MazeSize {
+            blocks_wide: 35 * scale,
+            blocks_high: 35 * scale,
+            loop_fill: 0.6,
+            max_gap: 3,
+            ground_density: 0.1,
+        }
+    }
+}
+
+pub struct Maze {
+    pub text: String,
+    pub loop_length: usize,
+    pub num_enclosed: usize,
+}
+
+fn get_pipe(connections: u8) -> char {
+    match connections {
+        c if c == NORTH | SOUTH => '|',
+        c if c == EAST | WEST => '-',
+        c if c == NORTH | EAST => 'L',
+        c if c == NORTH | WEST => 'J',
+        c if c == SOUTH | WEST => '7',
+        c if c == SOUTH | EAST => 'F',
+        _ => panic!("Connections invalid: {connections}"),
+    }
+}
+
+// connect, create_loop, get_loop_cells, get_direction and get_stretched_positions are kept
+// identical to 18/rust/src/generator.rs, the days do not share a library
+fn connect(connections: &mut [Vec<u8>], from: (usize, usize), to: (usize, usize)) {
+    let (direction_from, direction_to) = match (
+        to.0 as isize - from.0 as isize,
+        to.1 as isize - from.1 as isize,
+    ) {
+        (-1, 0) => (NORTH, SOUTH),
+        (1, 0) => (SOUTH, NORTH),
+        (0, -1) => (WEST, EAST),
+        (0, 1) => (EAST, WEST),
+        _ => panic!("Cells not next to each other: {from:?} {to:?}"),
+    };
+    connections[from.0][from.1] ^= direction_from;
+    connections[to.0][to.1] ^= direction_to;
+}
+
+// grows a random tree over blocks of 2x2 cells and walks around it, every cell of a block
+// in the tree ends up on one closed loop that never touches or crosses itself, returned as
+// connections per cell
+fn create_loop(
+    block_width: usize,
+    block_height: usize,
+    fill: f64,
+    rng: &mut StdRng,
+) -> Vec<Vec<u8>> {
+    let target_size = ((block_width * block_height) as f64 * fill).max(1.0) as usize;
+    let mut in_tree = vec![vec![false; block_width]; block_height];
+    let mut connections = vec![vec![0; 2 * block_width]; 2 * block_height];
+
+    let start = (
+        rng.gen_range(0..block_height),
+        rng.gen_range(0..block_width),
+    );
+    let mut tree = vec![start];
+    in_tree[start.0][start.1] = true;
+    let mut frontier = vec![];
+    loop {
+        let (y, x) = *tree.last().unwrap();
+        // every block starts as a small loop around its four cells
+        connect(&mut connections, (2 * y, 2 * x), (2 * y, 2 * x + 1));
+        connect(&mut connections, (2 * y, 2 * x + 1), (2 * y + 1, 2 * x + 1));
+        connect(&mut connections, (2 * y + 1, 2 * x + 1), (2 * y + 1, 2 * x));
+        connect(&mut connections, (2 * y + 1, 2 * x), (2 * y, 2 * x));
+        if tree.len() == target_size {
+            break;
+        }
+
+        if y > 0 {
+            frontier.push(((y, x), (y - 1, x)));
+        }
+        if y + 1 < block_height {
+            frontier.push(((y, x), (y + 1, x)));
+        }
+        if x > 0 {
+            frontier.push(((y, x), (y, x - 1)));
+        }
+        if x + 1 < block_width {
+            frontier.push(((y, x), (y, x + 1)));
+        }
+
+        let (parent, child) = loop {
+            let (parent, (y, x)) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+            if !in_tree[y][x] {
+                break (parent, (y, x));
+            }
+        };
+        in_tree[child.0][child.1] = true;
+        tree.push(child);
+
+        // merging two loops: cut the facing sides and bridge them, xor removes
+        // the cut connection and adds the bridge in one go
+        let (top_left, bottom_right) = (parent.min(child), parent.max(child));
+        let (y, x) = top_left;
+        if bottom_right.0 > y {
+            connect(&mut connections, (2 * y + 1, 2 * x), (2 * y + 1, 2 * x + 1));
+            connect(&mut connections, (2 * y + 2, 2 * x), (2 * y + 2, 2 * x + 1));
+            connect(&mut connections, (2 * y + 1, 2 * x), (2 * y + 2, 2 * x));
+            connect(
+                &mut connections,
+                (2 * y + 1, 2 * x + 1),
+                (2 * y + 2, 2 * x + 1),
+            );
+        } else {
+            connect(&mut connections, (2 * y, 2 * x + 1), (2 * y + 1, 2 * x + 1));
+            connect(&mut connections, (2 * y, 2 * x + 2), (2 * y + 1, 2 * x + 2));
+            connect(&mut connections, (2 * y, 2 * x + 1), (2 * y, 2 * x + 2));
+            connect(
+                &mut connections,
+                (2 * y + 1, 2 * x + 1),
+                (2 * y + 1, 2 * x + 2),
+            );
+        }
+    }
+    connections
+}
+
+fn get_loop_cells(connections: &[Vec<u8>], start: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut cells = vec![start];
+    let mut previous = start;
+    let mut current = start;
+    loop {
+        let (y, x) = current;
+        let neighbours = [
+            (NORTH, y.wrapping_sub(1), x),
+            (EAST, y, x + 1),
+            (SOUTH, y + 1, x),
+            (WEST, y, x.wrapping_sub(1)),
+        ];
+        let next = neighbours
+            .into_iter()
+            .filter(|(direction, _, _)| connections[y][x] & direction != 0)
+            .map(|(_, yy, xx)| (yy, xx))
+            .find(|cell| *cell != previous)
+            .unwrap();
+        if next == start {
+            return cells;
+        }
+        cells.push(next);
+        (previous, current) = (current, next);
+    }
+}
+
+fn get_direction(from: (usize, usize), to: (usize, usize)) -> u8 {
+    match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+        (Ordering::Less, _) => NORTH,
+        (Ordering::Greater, _) => SOUTH,
+        (_, Ordering::Less) => WEST,
+        _ => EAST,
+    }
+}
+
+// increasing positions with random gaps, so the loop keeps its shape but gets room inside
+fn get_stretched_positions(num_positions: usize, max_gap: usize, rng: &mut StdRng) -> Vec<usize> {
+    let mut positions = vec![rng.gen_range(0..max_gap)];
+    for _ in 1..num_positions {
+        positions.push(positions.last().unwrap() + rng.gen_range(1..=max_gap));
+    }
+    positions
+}
+
+pub fn generate_maze(size: &MazeSize, rng: &mut StdRng) -> Maze {
+    let connections = create_loop(size.blocks_wide, size.blocks_high, size.loop_fill, rng);
+    let loop_start = connections
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|c| *c != 0).map(|x| (y, x)))
+        .unwrap();
+    let contour = get_loop_cells(&connections, loop_start);
+
+    let ys = get_stretched_positions(2 * size.blocks_high, size.max_gap, rng);
+    let xs = get_stretched_positions(2 * size.blocks_wide, size.max_gap, rng);
+    let height = ys.last().unwrap() + 1 + rng.gen_range(0..size.max_gap);
+    let width = xs.last().unwrap() + 1 + rng.gen_range(0..size.max_gap);
+
+    let mut loop_cells = vec![];
+    for (from, to) in contour.iter().zip(contour.iter().cycle().skip(1)) {
+        let (mut y, mut x) = (ys[from.0], xs[from.1]);
+        let end = (ys[to.0], xs[to.1]);
+        let direction = get_direction((y, x), end);
+        while (y, x) != end {
+            loop_cells.push((y, x));
+            match direction {
+                NORTH => y -= 1,
+                SOUTH => y += 1,
+                WEST => x -= 1,
+                _ => x += 1,
+            }
+        }
+    }
+
+    let mut maze: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| match rng.gen_bool(size.ground_density) {
+                    true => '.',
+                    false => *PIPES.choose(rng).unwrap(),
+                })
+                .collect()
+        })
+        .collect();
+    let mut is_on_loop = vec![vec![false; width]; height];
+    for (index, (y, x)) in loop_cells.iter().enumerate() {
+        let previous = loop_cells[(index + loop_cells.len() - 1) % loop_cells.len()];
+        let next = loop_cells[(index + 1) % loop_cells.len()];
+        maze[*y][*x] = get_pipe(get_direction((*y, *x), previous) | get_direction((*y, *x), next));
+        is_on_loop[*y][*x] = true;
+    }
+
+    // no junk pipe may point at the start, its connections have to be unambiguous
+    let (start_y, start_x) = *loop_cells.choose(rng).unwrap();
+    maze[start_y][start_x] = 'S';
+    for (y, x) in [
+        (start_y.wrapping_sub(1), start_x),
+        (start_y + 1, start_x),
+        (start_y, start_x.wrapping_sub(1)),
+        (start_y, start_x + 1),
+    ] {
+        if y < height && x < width && !is_on_loop[y][x] {
+            maze[y][x] = '.';
+        }
+    }
+
+    // tiles inside the loop from shoelace and pick's theorem
+    let twice_area: isize = loop_cells
+        .iter()
+        .zip(loop_cells.iter().cycle().skip(1))
+        .map(|((y, x), (next_y, next_x))| {
+            *x as isize * *next_y as isize - *next_x as isize * *y as isize
+        })
+        .sum();
+    let num_enclosed = (twice_area.unsigned_abs() + 2 - loop_cells.len()) / 2;
+
+    let lines: Vec<String> = maze
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+    Maze {
+        text: lines.join(LINE_ENDING),
+        loop_length: loop_cells.len(),
+        num_enclosed,
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random pipe maze instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let maze = generate_maze(&MazeSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &maze.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "expected farthest distance {} and {} enclosed tiles",
+        maze.loop_length / 2,
+        maze.num_enclosed
+    );
+    true
+}
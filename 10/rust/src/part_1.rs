@@ -3,6 +3,8 @@ use std::{
     fs,
 };
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -197,7 +199,7 @@ fn _print_visited_maze(visited_maze: &Vec<Vec<i128>>) {
 pub fn main() {
     println!("PART 1 ------------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let maze = parse_input(&input);
     let mut visited_maze = get_non_visited_maze(&input);
     let bunny_pos = find_bunny_position(&maze);
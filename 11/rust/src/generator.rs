@@ -0,0 +1,138 @@
+use std::fs;
+
+use rand::{rngs::StdRng, seq::index, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // the image rows are split on `\r\n`
+
+pub struct GalaxySize {
+    pub width: usize,
+    pub height: usize,
+    pub num_galaxies: usize,
+    pub num_empty_rows: usize, // at least, rows without a galaxy by chance come on top
+    pub num_empty_cols: usize,
+}
+
+impl GalaxySize {
+    pub fn create(scale: usize) -> GalaxySize {
+        GalaxySize {
+            width: 140 * scale,
+            height: 140 * scale,
+            num_galaxies: 440 * scale * scale,
+            num_empty_rows: 8 * scale,
+            num_empty_cols: 8 * scale,
+        }
+    }
+}
+
+pub struct GalaxyMap {
+    pub text: String,
+    galaxies: Vec<(usize, usize)>,
+    empty_rows: Vec<bool>,
+    empty_cols: Vec<bool>,
+}
+
+// sum over all pairs of |a - b|, from the sorted positions in one pass
+fn get_pairwise_distance(mut positions: Vec<usize>) -> usize {
+    positions.sort();
+    let mut sum = 0;
+    let mut prefix = 0;
+    for (index, position) in positions.into_iter().enumerate() {
+        sum += index * position - prefix;
+        prefix += position;
+    }
+    sum
+}
+
+fn get_expanded(position: usize, is_empty: &[bool], expansion: usize) -> usize {
+    let num_empty_before = is_empty[..position].iter().filter(|empty| **empty).count();
+    position + num_empty_before * (expansion - 1)
+}
+
+impl GalaxyMap {
+    // every empty row and col is replaced by `expansion` of them
+    pub fn get_distance_sum(&self, expansion: usize) -> usize {
+        let (ys, xs): (Vec<usize>, Vec<usize>) = self
+            .galaxies
+            .iter()
+            .map(|(y, x)| {
+                (
+                    get_expanded(*y, &self.empty_rows, expansion),
+                    get_expanded(*x, &self.empty_cols, expansion),
+                )
+            })
+            .unzip();
+        get_pairwise_distance(ys) + get_pairwise_distance(xs)
+    }
+}
+
+pub fn generate_galaxy_map(size: &GalaxySize, rng: &mut StdRng) -> GalaxyMap {
+    let forced_empty_rows: Vec<usize> =
+        index::sample(rng, size.height, size.num_empty_rows).into_vec();
+    let forced_empty_cols: Vec<usize> =
+        index::sample(rng, size.width, size.num_empty_cols).into_vec();
+    let rows: Vec<usize> = (0..size.height)
+        .filter(|y| !forced_empty_rows.contains(y))
+        .collect();
+    let cols: Vec<usize> = (0..size.width)
+        .filter(|x| !forced_empty_cols.contains(x))
+        .collect();
+
+    let mut map = vec![vec!['.'; size.width]; size.height];
+    let num_cells = rows.len() * cols.len();
+    let galaxies: Vec<(usize, usize)> =
+        index::sample(rng, num_cells, size.num_galaxies.min(num_cells))
+            .into_iter()
+            .map(|cell| (rows[cell / cols.len()], cols[cell % cols.len()]))
+            .collect();
+    for (y, x) in galaxies.iter() {
+        map[*y][*x] = '#';
+    }
+
+    let empty_rows: Vec<bool> = map.iter().map(|row| !row.contains(&'#')).collect();
+    let empty_cols: Vec<bool> = (0..size.width)
+        .map(|x| map.iter().all(|row| row[x] == '.'))
+        .collect();
+    let lines: Vec<String> = map
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+    GalaxyMap {
+        text: lines.join(LINE_ENDING),
+        galaxies,
+        empty_rows,
+        empty_cols,
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random galaxy map instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let galaxy_map = generate_galaxy_map(&GalaxySize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &galaxy_map.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "expected sums {} and {}",
+        galaxy_map.get_distance_sum(2),
+        galaxy_map.get_distance_sum(1_000_000)
+    );
+    true
+}
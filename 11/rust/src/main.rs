@@ -1,7 +1,18 @@
+mod generator;
 mod part_1;
 mod part_2;
 
+// `--input=PATH` expands another image
+fn get_input_path(default: &str) -> String {
+    std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--input=")?.to_string()))
+        .unwrap_or(default.to_string())
+}
+
 fn main() {
+    if generator::main() {
+        return;
+    }
     part_1::main();
     part_2::main();
 }
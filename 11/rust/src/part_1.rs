@@ -1,5 +1,7 @@
 use std::{collections::HashMap, fs};
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -116,7 +118,7 @@ fn calculate_manhatten_distance(pos1: &(usize, usize), pos2: &(usize, usize)) ->
 pub fn main() {
     println!("PART 1 ------------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let mut galaxies = parse_input(&input);
     expand_space(&mut galaxies);
     print_galaxies(&galaxies);
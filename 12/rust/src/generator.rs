@@ -0,0 +1,91 @@
+use std::fs;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // the records are split on `\r\n`
+
+pub struct RecordsSize {
+    pub num_records: usize,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub damaged_share: f64,
+    pub unknown_share: f64,
+}
+
+impl RecordsSize {
+    pub fn create(scale: usize) -> RecordsSize {
+        RecordsSize {
+            num_records: 1000 * scale,
+            min_length: 3,
+            max_length: 20,
+            damaged_share: 0.5,
+            unknown_share: 0.5,
+        }
+    }
+}
+
+// a real row of springs with at least one damaged, then cells hidden behind `?`,
+// so the real row is always one of the arrangements
+fn generate_record(size: &RecordsSize, rng: &mut StdRng) -> String {
+    let length = rng.gen_range(size.min_length..=size.max_length);
+    let springs: Vec<bool> = loop {
+        let springs: Vec<bool> = (0..length)
+            .map(|_| rng.gen_bool(size.damaged_share))
+            .collect();
+        if springs.contains(&true) {
+            break springs;
+        }
+    };
+
+    let groups: Vec<String> = springs
+        .split(|is_damaged| !is_damaged)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len().to_string())
+        .collect();
+    let record: String = springs
+        .iter()
+        .map(
+            |is_damaged| match (rng.gen_bool(size.unknown_share), is_damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            },
+        )
+        .collect();
+    format!("{record} {}", groups.join(","))
+}
+
+pub fn generate_records(size: &RecordsSize, rng: &mut StdRng) -> String {
+    let lines: Vec<String> = (0..size.num_records)
+        .map(|_| generate_record(size, rng))
+        .collect();
+    lines.join(LINE_ENDING)
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes random spring records instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let records = generate_records(&RecordsSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, records).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    true
+}
@@ -1,9 +1,22 @@
+mod generator;
 mod part_1;
 mod part_2;
 mod part_2_cool;
 
+// `--input=PATH` counts the arrangements of other records
+fn get_input_path(default: &str) -> String {
+    std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--input=")?.to_string()))
+        .unwrap_or(default.to_string())
+}
+
 fn main() {
+    if generator::main() {
+        return;
+    }
     part_1::main();
     part_2::main();
     part_2_cool::main();
+    part_2::fold_growth::main();
+    part_2::arrangements::main();
 }
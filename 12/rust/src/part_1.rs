@@ -1,5 +1,7 @@
 use std::{collections::VecDeque, fs};
 
+use crate::get_input_path;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -159,7 +161,7 @@ fn parse_input(input: &String) -> Vec<HotSpring> {
 pub fn main() {
     println!("PART 1 ------------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let springs = parse_input(&input);
 
     // println!("springs {:?}", springs);
@@ -1,8 +1,18 @@
+pub mod arrangements;
+pub mod fold_growth;
+
 use std::{
     collections::{HashMap, VecDeque},
     fs,
 };
 
+use crate::get_input_path;
+
+const UNFOLD_COUNT: usize = 5;
+const UNFOLD_JOINER: char = '?';
+
+type MemoKey = (String, Vec<usize>, usize); // records, group sizes, size of the current group
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -24,13 +34,13 @@ impl Clone for HotSpring {
 }
 
 impl HotSpring {
-    fn create_unfolded(left: String, right: String) -> HotSpring {
+    fn create_unfolded(left: String, right: String, fold_count: usize, joiner: char) -> HotSpring {
         let base = HotSpring::create(left, right);
 
         let mut records = base.records.clone();
         let mut nums = base.nums.clone();
-        for _ in 0..(5 - 1) {
-            records.push_back('?');
+        for _ in 0..fold_count.saturating_sub(1) {
+            records.push_back(joiner);
             records.append(&mut base.records.clone());
 
             nums.append(&mut base.nums.clone());
@@ -89,34 +99,24 @@ impl HotSpring {
         print!("\n");
     }
 
-    pub fn get_num_arrangements(&self, mappa: &mut HashMap<String, usize>) -> usize {
+    pub fn get_num_arrangements(&self, mappa: &mut HashMap<MemoKey, usize>) -> usize {
         let mut input = self.clone();
         input.records.push_back('.');
 
         get_num_arrangements_recursive(input, 0, mappa)
     }
 
-    pub fn get_key(&self, size_of_current_group: usize) -> String {
-        let records = self
-            .records
-            .iter()
-            .map(|n| n.to_string())
-            .collect::<Vec<String>>()
-            .join("");
-
-        let nums = self
-            .nums
-            .iter()
-            .map(|n| n.to_string())
-            .collect::<Vec<String>>()
-            .join("_");
-
-        let size = size_of_current_group.to_string();
-        records + "_" + &nums + "_" + &size
+    // a tuple instead of a joined string, so no joiner can make two states collide
+    pub fn get_key(&self, size_of_current_group: usize) -> MemoKey {
+        (
+            self.records.iter().collect(),
+            self.nums.iter().copied().collect(),
+            size_of_current_group,
+        )
     }
 }
 
-fn parse_input(input: &String) -> Vec<HotSpring> {
+fn parse_input(input: &String, fold_count: usize, joiner: char) -> Vec<HotSpring> {
     input
         .split("\r\n")
         .into_iter()
@@ -124,7 +124,7 @@ fn parse_input(input: &String) -> Vec<HotSpring> {
             let mut splitted = line.split(" ");
             let left = splitted.next().unwrap();
             let right = splitted.next().unwrap();
-            HotSpring::create_unfolded(left.to_string(), right.to_string())
+            HotSpring::create_unfolded(left.to_string(), right.to_string(), fold_count, joiner)
         })
         .collect()
 }
@@ -132,7 +132,7 @@ fn parse_input(input: &String) -> Vec<HotSpring> {
 fn get_num_arrangements_recursive(
     spring: HotSpring,
     size_of_current_group: usize,
-    mappa: &mut HashMap<String, usize>,
+    mappa: &mut HashMap<MemoKey, usize>,
 ) -> usize {
     let key = spring.get_key(size_of_current_group);
     if let Some(pre_computed_result) = mappa.get(&key) {
@@ -164,7 +164,7 @@ fn handle_splits(
     v: char,
     spring: &HotSpring,
     size_of_current_group: usize,
-    mappa: &mut HashMap<String, usize>,
+    mappa: &mut HashMap<MemoKey, usize>,
 ) -> usize {
     let mut num_arrangements = 0;
 
@@ -220,10 +220,10 @@ fn handle_end(spring: &HotSpring, size_of_current_group: usize) -> Option<bool>
 pub fn main() {
     println!("PART 2 -----custom hashmap-------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
-    let springs = parse_input(&input);
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let springs = parse_input(&input, UNFOLD_COUNT, UNFOLD_JOINER);
 
-    let mut map_to_rule_them_all: HashMap<String, usize> = HashMap::new();
+    let mut map_to_rule_them_all: HashMap<MemoKey, usize> = HashMap::new();
 
     let all_sums: Vec<usize> = springs
         .into_iter()
@@ -0,0 +1,246 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{read_text, HotSpring};
+use crate::get_input_path;
+
+const NUM_PRINTED_ARRANGEMENTS: usize = 10;
+const SAMPLE_SEED: u64 = 2023; // fixed, so the sampled arrangements are the same every run
+
+// ways[i][j] = number of ways to resolve records[i..] with nums[j..],
+// where position i is not directly behind a group
+#[derive(Clone, Debug)]
+pub struct ArrangementTable {
+    records: Vec<char>,
+    nums: Vec<usize>,
+    ways: Vec<Vec<usize>>,
+}
+
+impl ArrangementTable {
+    pub fn create(spring: &HotSpring) -> ArrangementTable {
+        let records: Vec<char> = spring.records.iter().copied().collect();
+        let nums: Vec<usize> = spring.nums.iter().copied().collect();
+
+        let mut ways = vec![vec![0; nums.len() + 1]; records.len() + 1];
+        ways[records.len()][nums.len()] = 1;
+
+        for i in (0..records.len()).rev() {
+            for j in 0..=nums.len() {
+                let mut num_ways = 0;
+                if records[i] != '#' {
+                    num_ways += ways[i + 1][j];
+                }
+                if let Some(next_index) =
+                    ArrangementTable::get_index_after_group(&records, &nums, i, j)
+                {
+                    num_ways += ways[next_index][j + 1];
+                }
+                ways[i][j] = num_ways;
+            }
+        }
+
+        ArrangementTable {
+            records,
+            nums,
+            ways,
+        }
+    }
+
+    // index after group j placed at i, including the separating '.'
+    fn get_index_after_group(
+        records: &[char],
+        nums: &[usize],
+        i: usize,
+        j: usize,
+    ) -> Option<usize> {
+        let group_size = *nums.get(j)?;
+        let end = i + group_size;
+        if end > records.len() || records[i..end].contains(&'.') {
+            return None;
+        }
+        match records.get(end) {
+            None => Some(end),
+            Some('#') => None,
+            Some(_) => Some(end + 1),
+        }
+    }
+
+    pub fn get_num_arrangements(&self) -> usize {
+        self.ways[0][0]
+    }
+
+    pub fn get_nth_arrangement(&self, mut n: usize) -> Option<String> {
+        if n >= self.get_num_arrangements() {
+            return None;
+        }
+
+        let mut resolved = String::with_capacity(self.records.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.records.len() {
+            if self.records[i] != '#' {
+                let num_ways_with_dot = self.ways[i + 1][j];
+                if n < num_ways_with_dot {
+                    resolved.push('.');
+                    i += 1;
+                    continue;
+                }
+                n -= num_ways_with_dot;
+            }
+
+            // n is in range, so the group has to fit here
+            let next_index =
+                ArrangementTable::get_index_after_group(&self.records, &self.nums, i, j).unwrap();
+            let group_end = i + self.nums[j];
+            resolved.push_str(&"#".repeat(self.nums[j]));
+            resolved.push_str(&".".repeat(next_index - group_end));
+            i = next_index;
+            j += 1;
+        }
+        Some(resolved)
+    }
+
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Option<String> {
+        match self.get_num_arrangements() {
+            0 => None,
+            num_arrangements => self.get_nth_arrangement(rng.gen_range(0..num_arrangements)),
+        }
+    }
+
+    // Some('#') or Some('.') when every valid arrangement agrees on the position
+    pub fn get_forced_positions(&self) -> Vec<Option<char>> {
+        let num_arrangements = self.get_num_arrangements();
+        if num_arrangements == 0 {
+            return vec![None; self.records.len()];
+        }
+
+        // reached[i][j] = number of ways to resolve records[..i] with nums[..j]
+        let mut reached = vec![vec![0; self.nums.len() + 1]; self.records.len() + 1];
+        reached[0][0] = 1;
+        // damaged_delta is a difference array over arrangements having '#' at a position
+        let mut damaged_delta = vec![0_i128; self.records.len() + 1];
+
+        for i in 0..self.records.len() {
+            for j in 0..=self.nums.len() {
+                let num_reached = reached[i][j];
+                if num_reached == 0 {
+                    continue;
+                }
+                if self.records[i] != '#' {
+                    reached[i + 1][j] += num_reached;
+                }
+                if let Some(next_index) =
+                    ArrangementTable::get_index_after_group(&self.records, &self.nums, i, j)
+                {
+                    reached[next_index][j + 1] += num_reached;
+
+                    let num_through = num_reached as i128 * self.ways[next_index][j + 1] as i128;
+                    damaged_delta[i] += num_through;
+                    damaged_delta[i + self.nums[j]] -= num_through;
+                }
+            }
+        }
+
+        let mut num_damaged = 0;
+        damaged_delta
+            .into_iter()
+            .take(self.records.len())
+            .map(|delta| {
+                num_damaged += delta;
+                match num_damaged {
+                    0 => Some('.'),
+                    n if n == num_arrangements as i128 => Some('#'),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct Arrangements {
+    table: ArrangementTable,
+    next_index: usize,
+}
+
+impl Iterator for Arrangements {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let arrangement = self.table.get_nth_arrangement(self.next_index)?;
+        self.next_index += 1;
+        Some(arrangement)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_left = self.table.get_num_arrangements() - self.next_index;
+        (num_left, Some(num_left))
+    }
+}
+
+impl ExactSizeIterator for Arrangements {}
+
+impl HotSpring {
+    pub fn get_arrangements(&self) -> Arrangements {
+        Arrangements {
+            table: ArrangementTable::create(self),
+            next_index: 0,
+        }
+    }
+
+    pub fn sample_arrangement<R: Rng>(&self, rng: &mut R) -> Option<String> {
+        ArrangementTable::create(self).sample(rng)
+    }
+
+    pub fn get_forced_positions(&self) -> Vec<Option<char>> {
+        ArrangementTable::create(self).get_forced_positions()
+    }
+}
+
+fn print_arrangement_details(line: &str, spring: &HotSpring, rng: &mut StdRng) {
+    let arrangements = spring.get_arrangements();
+    let forced: String = spring
+        .get_forced_positions()
+        .into_iter()
+        .map(|c| c.unwrap_or('?'))
+        .collect();
+
+    println!("{line} : {} arrangements", arrangements.len());
+    println!("    forced  {forced}");
+    println!("    sampled {:?}", spring.sample_arrangement(rng));
+    for arrangement in arrangements.take(NUM_PRINTED_ARRANGEMENTS) {
+        println!("            {arrangement}");
+    }
+}
+
+// `--arrangements` lists the arrangements of the impossible and the most ambiguous records
+pub fn main() {
+    if !std::env::args().any(|arg| arg == "--arrangements") {
+        return;
+    }
+    println!("PART 2 -----arrangements-------");
+
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let all_springs: Vec<(&str, HotSpring, usize)> = input
+        .split("\r\n")
+        .map(|line| {
+            let mut splitted = line.split(' ');
+            let left = splitted.next().unwrap();
+            let right = splitted.next().unwrap();
+            let spring = HotSpring::create(left.to_string(), right.to_string());
+            let num_arrangements = spring.get_arrangements().len();
+            (line, spring, num_arrangements)
+        })
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(SAMPLE_SEED);
+    for (line, spring, num_arrangements) in all_springs.iter() {
+        if *num_arrangements == 0 {
+            print_arrangement_details(line, spring, &mut rng);
+        }
+    }
+
+    if let Some((line, spring, _)) = all_springs
+        .iter()
+        .max_by_key(|(_, _, num_arrangements)| *num_arrangements)
+    {
+        print_arrangement_details(line, spring, &mut rng);
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use super::{read_text, HotSpring, MemoKey, UNFOLD_COUNT, UNFOLD_JOINER};
+use crate::get_input_path;
+
+const MAX_ANALYSED_FOLDS: usize = 4;
+const MIN_EQUAL_RATIOS: usize = 2;
+
+// Two equal ratios are only a hint that the growth is geometric, so `analyse` also counts one
+// fold past the analysed ones and keeps the ratio only if it predicts that count exactly.
+// A verified ratio is still a heuristic for the folds beyond.
+
+#[derive(Clone, Debug)]
+pub struct FoldGrowth {
+    pub counts: Vec<usize>, // counts[i] = arrangements when folded i + 1 times, one past max_folds
+    pub settled_at: Option<usize>, // first fold from which the ratio stays fixed, verified
+    pub ratio: Option<(usize, usize)>, // (numerator, denominator), reduced
+}
+
+impl FoldGrowth {
+    pub fn analyse(
+        left: &str,
+        right: &str,
+        max_folds: usize,
+        joiner: char,
+        mappa: &mut HashMap<MemoKey, usize>,
+    ) -> FoldGrowth {
+        let counts: Vec<usize> = (1..=max_folds + 1)
+            .map(|fold_count| {
+                HotSpring::create_unfolded(left.to_string(), right.to_string(), fold_count, joiner)
+                    .get_num_arrangements(mappa)
+            })
+            .collect();
+
+        // the fold found on the analysed counts has to survive the extra, actually counted fold
+        let predicted = FoldGrowth::find_settled_fold(&counts[..max_folds]);
+        let verified = FoldGrowth::find_settled_fold(&counts);
+        let settled_at = predicted.filter(|_| predicted == verified);
+        let ratio = settled_at.map(|fold| {
+            let current = counts[fold - 1];
+            let next = counts[fold];
+            match current {
+                0 => (0, 1),
+                _ => {
+                    let divisor = gcd(next, current);
+                    (next / divisor, current / divisor)
+                }
+            }
+        });
+
+        FoldGrowth {
+            counts,
+            settled_at,
+            ratio,
+        }
+    }
+
+    fn find_settled_fold(counts: &[usize]) -> Option<usize> {
+        // the ratio counts[i + 1] / counts[i] has to hold for every following fold
+        let num_ratios = counts.len().saturating_sub(1);
+        (0..num_ratios.saturating_sub(MIN_EQUAL_RATIOS - 1))
+            .find(|&start| {
+                (start + 1..num_ratios).all(|i| match counts[start] {
+                    0 => counts[i + 1] == 0,
+                    _ => {
+                        counts[i] != 0
+                            && counts[i + 1] as u128 * counts[start] as u128
+                                == counts[i] as u128 * counts[start + 1] as u128
+                    }
+                })
+            })
+            .map(|start| start + 1)
+    }
+
+    pub fn extrapolate(&self, fold_count: usize) -> Option<u128> {
+        if fold_count == 0 {
+            return None;
+        }
+        if fold_count <= self.counts.len() {
+            return Some(self.counts[fold_count - 1] as u128);
+        }
+
+        let (numerator, denominator) = self.ratio?;
+        let mut value = *self.counts.last()? as u128;
+        for _ in self.counts.len()..fold_count {
+            let scaled = value.checked_mul(numerator as u128)?;
+            if scaled % denominator as u128 != 0 {
+                return None;
+            }
+            value = scaled / denominator as u128;
+        }
+        Some(value)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+pub fn analyse_input(input: &str, max_folds: usize, joiner: char) -> Vec<(String, FoldGrowth)> {
+    let mut mappa: HashMap<MemoKey, usize> = HashMap::new();
+
+    input
+        .split("\r\n")
+        .map(|line| {
+            let mut splitted = line.split(' ');
+            let left = splitted.next().unwrap();
+            let right = splitted.next().unwrap();
+            let growth = FoldGrowth::analyse(left, right, max_folds, joiner, &mut mappa);
+            (line.to_string(), growth)
+        })
+        .collect()
+}
+
+// `--fold-growth` prints how the arrangement counts grow with every fold
+pub fn main() {
+    if !std::env::args().any(|arg| arg == "--fold-growth") {
+        return;
+    }
+    println!("PART 2 -----fold growth-------");
+
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let all_growths = analyse_input(&input, MAX_ANALYSED_FOLDS, UNFOLD_JOINER);
+
+    for (line, growth) in all_growths.iter() {
+        match (growth.settled_at, growth.ratio) {
+            (Some(fold), Some((numerator, denominator))) => println!(
+                "{line} : {:?} settles at fold {fold} with ratio {numerator}/{denominator}",
+                growth.counts
+            ),
+            _ => println!("{line} : {:?} not settled", growth.counts),
+        }
+    }
+
+    let num_settled = all_growths
+        .iter()
+        .filter(|(_, growth)| growth.settled_at.is_some())
+        .count();
+    println!("settled lines {num_settled} / {}", all_growths.len());
+
+    let extrapolated: u128 = all_growths
+        .iter()
+        .filter_map(|(_, growth)| growth.extrapolate(UNFOLD_COUNT))
+        .sum();
+    println!("extrapolated sum of settled lines for {UNFOLD_COUNT} folds {extrapolated}");
+}
@@ -1,6 +1,11 @@
 use cached::proc_macro::cached;
 use std::{collections::VecDeque, fs};
 
+use crate::get_input_path;
+
+const UNFOLD_COUNT: usize = 5;
+const UNFOLD_JOINER: char = '?';
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
@@ -17,13 +22,18 @@ fn create(left: String, right: String) -> (VecDeque<char>, VecDeque<usize>) {
     (records, nums)
 }
 
-fn create_unfolded(left: String, right: String) -> (VecDeque<char>, VecDeque<usize>) {
+fn create_unfolded(
+    left: String,
+    right: String,
+    fold_count: usize,
+    joiner: char,
+) -> (VecDeque<char>, VecDeque<usize>) {
     let (base_records, base_nums) = create(left, right);
 
     let mut records = base_records.clone();
     let mut nums = base_nums.clone();
-    for _ in 0..(5 - 1) {
-        records.push_back('?');
+    for _ in 0..fold_count.saturating_sub(1) {
+        records.push_back(joiner);
         records.append(&mut base_records.clone());
 
         nums.append(&mut base_nums.clone());
@@ -129,7 +139,11 @@ fn handle_end(
     None
 }
 
-fn parse_input(input: &String) -> Vec<(VecDeque<char>, VecDeque<usize>)> {
+fn parse_input(
+    input: &String,
+    fold_count: usize,
+    joiner: char,
+) -> Vec<(VecDeque<char>, VecDeque<usize>)> {
     input
         .split("\r\n")
         .into_iter()
@@ -137,7 +151,7 @@ fn parse_input(input: &String) -> Vec<(VecDeque<char>, VecDeque<usize>)> {
             let mut splitted = line.split(" ");
             let left = splitted.next().unwrap();
             let right = splitted.next().unwrap();
-            create_unfolded(left.to_string(), right.to_string())
+            create_unfolded(left.to_string(), right.to_string(), fold_count, joiner)
         })
         .collect()
 }
@@ -145,8 +159,8 @@ fn parse_input(input: &String) -> Vec<(VecDeque<char>, VecDeque<usize>)> {
 pub fn main() {
     println!("PART 2 -----cached = 0.46.1 -------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
-    let springs = parse_input(&input);
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let springs = parse_input(&input, UNFOLD_COUNT, UNFOLD_JOINER);
 
     let all_sums: Vec<usize> = springs
         .into_iter()
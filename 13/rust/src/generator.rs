@@ -0,0 +1,172 @@
+use std::fs;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::mirror_field::{Axis, MirrorField, Reflection};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\r\n"; // MirrorField::create splits the rows on `\r\n`
+
+pub struct PatternsSize {
+    pub num_patterns: usize,
+    pub min_side: usize,
+    pub max_side: usize,
+}
+
+impl PatternsSize {
+    pub fn create(scale: usize) -> PatternsSize {
+        PatternsSize {
+            num_patterns: 100 * scale,
+            min_side: 5,
+            max_side: 17,
+        }
+    }
+}
+
+pub struct Patterns {
+    pub text: String,
+    pub reflections: Vec<Reflection>,
+    pub smudged_reflections: Vec<Reflection>,
+}
+
+fn find(parents: &mut [usize], cell: usize) -> usize {
+    let mut root = cell;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[cell] = root;
+    root
+}
+
+// pairs of cells (y * width + x) that the mirror line maps onto each other
+fn get_mirrored_pairs(reflection: &Reflection, height: usize, width: usize) -> Vec<(usize, usize)> {
+    let (num_lines, line_length) = match reflection.axis {
+        Axis::Horizontal => (height, width),
+        Axis::Vertical => (width, height),
+    };
+    let mut pairs = vec![];
+    for (left, right) in (0..reflection.index).rev().zip(reflection.index..num_lines) {
+        for along in 0..line_length {
+            pairs.push(match reflection.axis {
+                Axis::Horizontal => (left * width + along, right * width + along),
+                Axis::Vertical => (along * width + left, along * width + right),
+            });
+        }
+    }
+    pairs
+}
+
+fn get_random_reflection(height: usize, width: usize, rng: &mut StdRng) -> Reflection {
+    let (axis, num_lines) = match rng.gen_bool(0.5) {
+        true => (Axis::Horizontal, height),
+        false => (Axis::Vertical, width),
+    };
+    Reflection {
+        axis,
+        index: rng.gen_range(1..num_lines),
+        num_mismatches: 0,
+    }
+}
+
+// cells tied together by a perfect mirror line and by a second line that is off by exactly one
+// cell, retried until the pattern has no other line with zero or one mismatch
+fn generate_pattern(size: &PatternsSize, rng: &mut StdRng) -> (String, Reflection, Reflection) {
+    loop {
+        let height = rng.gen_range(size.min_side..=size.max_side);
+        let width = rng.gen_range(size.min_side..=size.max_side);
+        let reflection = get_random_reflection(height, width, rng);
+        let mut smudged = get_random_reflection(height, width, rng);
+        smudged.num_mismatches = 1;
+        if (smudged.axis, smudged.index) == (reflection.axis, reflection.index) {
+            continue;
+        }
+
+        let mut smudged_pairs = get_mirrored_pairs(&smudged, height, width);
+        let smudge = smudged_pairs.swap_remove(rng.gen_range(0..smudged_pairs.len()));
+
+        let mut parents: Vec<usize> = (0..height * width).collect();
+        for (a, b) in get_mirrored_pairs(&reflection, height, width)
+            .into_iter()
+            .chain(smudged_pairs)
+        {
+            let (root_a, root_b) = (find(&mut parents, a), find(&mut parents, b));
+            parents[root_a] = root_b;
+        }
+        let (root_a, root_b) = (find(&mut parents, smudge.0), find(&mut parents, smudge.1));
+        if root_a == root_b {
+            continue;
+        }
+
+        let mut is_rock: Vec<bool> = (0..height * width).map(|_| rng.gen_bool(0.5)).collect();
+        is_rock[root_b] = !is_rock[root_a];
+        let cells: Vec<char> = (0..height * width)
+            .map(|cell| match is_rock[find(&mut parents, cell)] {
+                true => '#',
+                false => '.',
+            })
+            .collect();
+        let lines: Vec<String> = cells
+            .chunks(width)
+            .map(|line| line.iter().collect())
+            .collect();
+        let block = lines.join(LINE_ENDING);
+
+        let field = MirrorField::create(&block);
+        if field.get_reflections(0) == [reflection] && field.get_reflections(1) == [smudged] {
+            return (block, reflection, smudged);
+        }
+    }
+}
+
+pub fn generate_patterns(size: &PatternsSize, rng: &mut StdRng) -> Patterns {
+    let mut blocks = vec![];
+    let mut reflections = vec![];
+    let mut smudged_reflections = vec![];
+    for _ in 0..size.num_patterns {
+        let (block, reflection, smudged) = generate_pattern(size, rng);
+        blocks.push(block);
+        reflections.push(reflection);
+        smudged_reflections.push(smudged);
+    }
+
+    Patterns {
+        text: blocks.join(&LINE_ENDING.repeat(2)),
+        reflections,
+        smudged_reflections,
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes random mirror patterns instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let patterns = generate_patterns(&PatternsSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &patterns.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+
+    let get_sum =
+        |reflections: &[Reflection]| -> usize { reflections.iter().map(|r| r.get_summary()).sum() };
+    println!(
+        "expected sums {} and {}",
+        get_sum(&patterns.reflections),
+        get_sum(&patterns.smudged_reflections)
+    );
+    true
+}
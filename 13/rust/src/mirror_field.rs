@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+const BITS_PER_CHUNK: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal, // mirror line between two rows
+    Vertical,   // mirror line between two columns
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub index: usize, // number of rows (or cols) above (or left of) the mirror line
+    pub num_mismatches: usize,
+}
+
+impl Reflection {
+    pub fn get_summary(&self) -> usize {
+        match self.axis {
+            Axis::Horizontal => self.index * 100,
+            Axis::Vertical => self.index,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    Rotational,   // 180° rotation
+    MainDiagonal, // top left to bottom right, square patterns only
+    AntiDiagonal, // top right to bottom left, square patterns only
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Window {
+    pub top: usize,
+    pub left: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct MirrorField {
+    lines: Vec<String>,
+
+    // all four share one fingerprint space, so rows can be compared to (reversed) cols
+    row_hashs: Vec<usize>,
+    col_hashs: Vec<usize>,
+    reversed_row_hashs: Vec<usize>,
+    reversed_col_hashs: Vec<usize>,
+
+    row_masks: Vec<Vec<u64>>,
+    col_masks: Vec<Vec<u64>>,
+}
+
+impl MirrorField {
+    pub fn create(block: &str) -> MirrorField {
+        let lines: Vec<String> = block
+            .split("\r\n")
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect();
+
+        MirrorField::create_from_lines(lines)
+    }
+
+    fn create_from_lines(lines: Vec<String>) -> MirrorField {
+        let transposed = MirrorField::transpose(&lines);
+        let reverse = |lines: &[String]| -> Vec<String> {
+            lines
+                .iter()
+                .map(|line| line.chars().rev().collect())
+                .collect()
+        };
+
+        let all_lines = [
+            lines.clone(),
+            transposed.clone(),
+            reverse(&lines),
+            reverse(&transposed),
+        ]
+        .concat();
+        let mut all_hashs = MirrorField::get_line_map(&all_lines).into_iter();
+        let mut take_hashs = |len: usize| -> Vec<usize> { all_hashs.by_ref().take(len).collect() };
+
+        MirrorField {
+            row_hashs: take_hashs(lines.len()),
+            col_hashs: take_hashs(transposed.len()),
+            reversed_row_hashs: take_hashs(lines.len()),
+            reversed_col_hashs: take_hashs(transposed.len()),
+            row_masks: MirrorField::get_line_masks(&lines),
+            col_masks: MirrorField::get_line_masks(&transposed),
+            lines,
+        }
+    }
+
+    fn get_line_map(lines: &[String]) -> Vec<usize> {
+        let line_map: HashMap<&String, usize> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| (line, index))
+            .collect();
+
+        lines.iter().map(|line| line_map[line]).collect()
+    }
+
+    fn get_line_masks(lines: &[String]) -> Vec<Vec<u64>> {
+        lines
+            .iter()
+            .map(|line| {
+                let mut mask = vec![0_u64; line.len().div_ceil(BITS_PER_CHUNK)];
+                for (index, c) in line.chars().enumerate() {
+                    if c == '#' {
+                        mask[index / BITS_PER_CHUNK] |= 1 << (index % BITS_PER_CHUNK);
+                    }
+                }
+                mask
+            })
+            .collect()
+    }
+
+    fn transpose(lines: &[String]) -> Vec<String> {
+        let lines: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+
+        let rows = lines.len();
+        let cols = lines.first().map_or(0, |line| line.len());
+
+        (0..cols)
+            .map(|col| (0..rows).map(|row| lines[row][col]).collect())
+            .collect()
+    }
+
+    fn get_hashs_and_masks(&self, axis: Axis) -> (&Vec<usize>, &Vec<Vec<u64>>) {
+        match axis {
+            Axis::Horizontal => (&self.row_hashs, &self.row_masks),
+            Axis::Vertical => (&self.col_hashs, &self.col_masks),
+        }
+    }
+
+    // counts differing cells across the mirror line, stops early once max_mismatches is exceeded
+    fn count_mismatches(&self, axis: Axis, index: usize, max_mismatches: usize) -> Option<usize> {
+        let (hashs, masks) = self.get_hashs_and_masks(axis);
+
+        let mut num_mismatches = 0;
+        for (left, right) in (0..index).rev().zip(index..hashs.len()) {
+            if hashs[left] == hashs[right] {
+                continue;
+            }
+
+            num_mismatches += masks[left]
+                .iter()
+                .zip(masks[right].iter())
+                .map(|(l, r)| (l ^ r).count_ones() as usize)
+                .sum::<usize>();
+            if num_mismatches > max_mismatches {
+                return None;
+            }
+        }
+        Some(num_mismatches)
+    }
+
+    fn get_reflections_along(&self, axis: Axis, num_smudges: usize) -> Vec<Reflection> {
+        let (hashs, _) = self.get_hashs_and_masks(axis);
+
+        (1..hashs.len())
+            .filter(|index| self.count_mismatches(axis, *index, num_smudges) == Some(num_smudges))
+            .map(|index| Reflection {
+                axis,
+                index,
+                num_mismatches: num_smudges,
+            })
+            .collect()
+    }
+
+    // every mirror line whose two sides differ in exactly num_smudges cells
+    pub fn get_reflections(&self, num_smudges: usize) -> Vec<Reflection> {
+        let mut reflections = self.get_reflections_along(Axis::Horizontal, num_smudges);
+        reflections.append(&mut self.get_reflections_along(Axis::Vertical, num_smudges));
+        reflections
+    }
+
+    // cells (row, col) on the upper / left side of the mirror line that have to be flipped
+    pub fn get_smudges(&self, reflection: &Reflection) -> Vec<(usize, usize)> {
+        let (hashs, masks) = self.get_hashs_and_masks(reflection.axis);
+
+        let mut smudges = vec![];
+        for (left, right) in (0..reflection.index)
+            .rev()
+            .zip(reflection.index..hashs.len())
+        {
+            for (chunk_index, (l, r)) in masks[left].iter().zip(masks[right].iter()).enumerate() {
+                let mut differing = l ^ r;
+                while differing != 0 {
+                    let position =
+                        chunk_index * BITS_PER_CHUNK + differing.trailing_zeros() as usize;
+                    differing &= differing - 1;
+
+                    smudges.push(match reflection.axis {
+                        Axis::Horizontal => (left, position),
+                        Axis::Vertical => (position, left),
+                    });
+                }
+            }
+        }
+        smudges.sort();
+        smudges
+    }
+
+    pub fn get_corrected_lines(&self, reflection: &Reflection) -> Vec<String> {
+        let mut grid: Vec<Vec<char>> = self
+            .lines
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+        for (row, col) in self.get_smudges(reflection) {
+            grid[row][col] = match grid[row][col] {
+                '#' => '.',
+                _ => '#',
+            };
+        }
+        grid.into_iter()
+            .map(|line| line.into_iter().collect())
+            .collect()
+    }
+
+    // corrected pattern with the mirror line drawn in as '-' (between rows) or '|' (between cols)
+    pub fn render_corrected(&self, reflection: &Reflection) -> String {
+        let mut corrected = self.get_corrected_lines(reflection);
+        match reflection.axis {
+            Axis::Horizontal => {
+                let width = corrected.first().map_or(0, |line| line.len());
+                corrected.insert(reflection.index, "-".repeat(width));
+            }
+            Axis::Vertical => {
+                for line in corrected.iter_mut() {
+                    line.insert(reflection.index, '|');
+                }
+            }
+        }
+        corrected.join("\n")
+    }
+
+    pub fn get_full_window(&self) -> Window {
+        Window {
+            top: 0,
+            left: 0,
+            height: self.row_hashs.len(),
+            width: self.col_hashs.len(),
+        }
+    }
+
+    // the part of the pattern covered by window, None if it does not fit
+    pub fn get_window(&self, window: &Window) -> Option<MirrorField> {
+        let is_empty = window.height == 0 || window.width == 0;
+        let does_fit = window.top + window.height <= self.row_hashs.len()
+            && window.left + window.width <= self.col_hashs.len();
+        if is_empty || !does_fit {
+            return None;
+        }
+
+        let lines = self.lines[window.top..window.top + window.height]
+            .iter()
+            .map(|line| line[window.left..window.left + window.width].to_string())
+            .collect();
+        Some(MirrorField::create_from_lines(lines))
+    }
+
+    pub fn has_symmetry(&self, symmetry: Symmetry) -> bool {
+        let num_rows = self.row_hashs.len();
+        let is_square = num_rows == self.col_hashs.len();
+
+        match symmetry {
+            Symmetry::Rotational => (0..num_rows)
+                .all(|row| self.row_hashs[row] == self.reversed_row_hashs[num_rows - 1 - row]),
+            Symmetry::MainDiagonal => {
+                is_square && (0..num_rows).all(|i| self.row_hashs[i] == self.col_hashs[i])
+            }
+            Symmetry::AntiDiagonal => {
+                is_square
+                    && (0..num_rows)
+                        .all(|i| self.row_hashs[i] == self.reversed_col_hashs[num_rows - 1 - i])
+            }
+        }
+    }
+
+    pub fn get_symmetries(&self) -> Vec<Symmetry> {
+        [
+            Symmetry::Rotational,
+            Symmetry::MainDiagonal,
+            Symmetry::AntiDiagonal,
+        ]
+        .into_iter()
+        .filter(|symmetry| self.has_symmetry(*symmetry))
+        .collect()
+    }
+
+    pub fn has_symmetry_in(&self, symmetry: Symmetry, window: &Window) -> bool {
+        self.get_window(window)
+            .is_some_and(|field| field.has_symmetry(symmetry))
+    }
+
+    // every placement of a height x width window that has the symmetry
+    pub fn find_symmetric_windows(
+        &self,
+        symmetry: Symmetry,
+        height: usize,
+        width: usize,
+    ) -> Vec<Window> {
+        let num_tops = (self.row_hashs.len() + 1).saturating_sub(height);
+        let num_lefts = (self.col_hashs.len() + 1).saturating_sub(width);
+
+        (0..num_tops)
+            .flat_map(|top| {
+                (0..num_lefts).map(move |left| Window {
+                    top,
+                    left,
+                    height,
+                    width,
+                })
+            })
+            .filter(|window| self.has_symmetry_in(symmetry, window))
+            .collect()
+    }
+
+    pub fn _print(&self) {
+        for l in self.lines.iter() {
+            println!("{:?}", l);
+        }
+        println!("self.row_hashs : {:?}", self.row_hashs);
+        println!("self.col_hashs : {:?}", self.col_hashs);
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<MirrorField> {
+    input.split("\r\n\r\n").map(MirrorField::create).collect()
+}
@@ -0,0 +1,53 @@
+use std::fs;
+
+use crate::get_input_path;
+use crate::mirror_field::{parse_input, MirrorField, Symmetry, Window};
+
+const MIN_WINDOW_SIZE: usize = 2;
+
+fn read_text(file_path: String) -> String {
+    fs::read_to_string(file_path).expect("Should have been able to read the file")
+}
+
+fn find_largest_symmetric_squares(field: &MirrorField, symmetry: Symmetry) -> Vec<Window> {
+    let full_window = field.get_full_window();
+    let max_size = full_window.height.min(full_window.width);
+
+    (MIN_WINDOW_SIZE..=max_size)
+        .rev()
+        .map(|size| field.find_symmetric_windows(symmetry, size, size))
+        .find(|windows| !windows.is_empty())
+        .unwrap_or_default()
+}
+
+// `--symmetries` prints the rotational and diagonal symmetries of every field
+pub fn main() {
+    if !std::env::args().any(|arg| arg == "--symmetries") {
+        return;
+    }
+    println!("SYMMETRIES ------------");
+
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let fields = parse_input(&input);
+
+    for (field_index, field) in fields.iter().enumerate() {
+        println!("field {field_index}: {:?}", field.get_symmetries());
+
+        for symmetry in [
+            Symmetry::Rotational,
+            Symmetry::MainDiagonal,
+            Symmetry::AntiDiagonal,
+        ] {
+            let windows = find_largest_symmetric_squares(field, symmetry);
+            if let Some(window) = windows.first() {
+                println!(
+                    "    {symmetry:?} largest square {0}x{0}, found {1} times, first at ({2}, {3})",
+                    window.height,
+                    windows.len(),
+                    window.top,
+                    window.left
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::Instant;
+
+use crate::hash::HashCreator;
+use crate::holiday_map::{Box, VecDequeBox};
+
+const NUM_STEPS: usize = 200_000;
+const NUM_LABELS: usize = 2_000;
+const CROWDED_BOX_INDEX: u8 = 0;
+const SEED: u64 = 15;
+
+trait LenseBox {
+    fn insert_lense(&mut self, label: &str, focal_length: u8);
+    fn remove_lense(&mut self, label: &str);
+    fn get_focusing_power(&self) -> u128;
+}
+
+impl LenseBox for Box<String, u8> {
+    fn insert_lense(&mut self, label: &str, focal_length: u8) {
+        self.insert(label.to_string(), focal_length);
+    }
+
+    fn remove_lense(&mut self, label: &str) {
+        self.remove(&label.to_string());
+    }
+
+    fn get_focusing_power(&self) -> u128 {
+        get_focusing_power(self.iter().map(|(_, focal_length)| *focal_length))
+    }
+}
+
+impl LenseBox for VecDequeBox<String, u8> {
+    fn insert_lense(&mut self, label: &str, focal_length: u8) {
+        self.insert(label.to_string(), focal_length);
+    }
+
+    fn remove_lense(&mut self, label: &str) {
+        self.remove(&label.to_string());
+    }
+
+    fn get_focusing_power(&self) -> u128 {
+        get_focusing_power(self.iter().map(|(_, focal_length)| *focal_length))
+    }
+}
+
+fn get_focusing_power(focal_lengths: impl Iterator<Item = u8>) -> u128 {
+    focal_lengths
+        .enumerate()
+        .map(|(lense_index, focal_length)| (lense_index + 1) as u128 * focal_length as u128)
+        .sum()
+}
+
+// xorshift, good enough to shuffle steps reproducibly
+fn get_next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+// labels that all end up in the same box, the worst case for shifting removals
+fn create_crowded_labels(num_labels: usize) -> Vec<String> {
+    (0_u64..)
+        .map(|n| {
+            let mut label = String::new();
+            let mut rest = n;
+            loop {
+                label.push((b'a' + (rest % 26) as u8) as char);
+                rest /= 26;
+                if rest == 0 {
+                    break label;
+                }
+            }
+        })
+        .filter(|label| HashCreator::get_hash(label.as_bytes()) == CROWDED_BOX_INDEX)
+        .take(num_labels)
+        .collect()
+}
+
+// (label index, Some(focal_length) for '=' or None for '-')
+fn create_steps(num_steps: usize, num_labels: usize) -> Vec<(usize, Option<u8>)> {
+    let mut state = SEED;
+    (0..num_steps)
+        .map(|_| {
+            let label_index = get_next_random(&mut state) as usize % num_labels;
+            let roll = get_next_random(&mut state);
+            match roll % 5 {
+                0 | 1 => (label_index, None),
+                _ => (label_index, Some((roll % 9 + 1) as u8)),
+            }
+        })
+        .collect()
+}
+
+fn run_steps(
+    lense_box: &mut impl LenseBox,
+    labels: &[String],
+    steps: &[(usize, Option<u8>)],
+) -> (u128, f64) {
+    let start = Instant::now();
+    for (label_index, focal_length) in steps {
+        match focal_length {
+            Some(focal_length) => lense_box.insert_lense(&labels[*label_index], *focal_length),
+            None => lense_box.remove_lense(&labels[*label_index]),
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    (lense_box.get_focusing_power(), elapsed)
+}
+
+// `--benchmark` races the linked Box against the VecDeque one instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--benchmark") {
+        return false;
+    }
+    println!("BENCHMARK ------------");
+
+    let labels = create_crowded_labels(NUM_LABELS);
+    let steps = create_steps(NUM_STEPS, NUM_LABELS);
+    println!("{NUM_STEPS} steps over {NUM_LABELS} labels in box {CROWDED_BOX_INDEX}");
+
+    let (power, seconds) = run_steps(&mut Box::create_empty(), &labels, &steps);
+    println!("linked Box   : focusing_power = {power}, {seconds:.4}s");
+
+    let (legacy_power, legacy_seconds) =
+        run_steps(&mut VecDequeBox::create_empty(), &labels, &steps);
+    println!("VecDeque Box : focusing_power = {legacy_power}, {legacy_seconds:.4}s");
+
+    assert_eq!(
+        power, legacy_power,
+        "both boxes should end in the same state"
+    );
+    true
+}
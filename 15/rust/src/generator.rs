@@ -0,0 +1,139 @@
+use std::{collections::HashSet, fs};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::hash::HashCreator;
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const NUM_BOXES: usize = 256;
+
+pub struct StepsSize {
+    pub num_steps: usize,
+    pub num_labels: usize,
+    pub max_label_length: usize,
+    pub remove_share: f64,
+}
+
+impl StepsSize {
+    pub fn create(scale: usize) -> StepsSize {
+        StepsSize {
+            num_steps: 4000 * scale,
+            num_labels: 500 * scale,
+            max_label_length: 6,
+            remove_share: 0.4,
+        }
+    }
+}
+
+pub struct Steps {
+    pub text: String,
+    steps: Vec<(String, Option<u8>)>,
+}
+
+impl Steps {
+    pub fn get_hash_sum(&self) -> usize {
+        self.text
+            .split(',')
+            .map(|step| HashCreator::get_hash(step.as_bytes()) as usize)
+            .sum()
+    }
+
+    // plain vectors as boxes, independent from the HolidayMap part 2 runs on
+    pub fn get_focusing_power(&self) -> usize {
+        let mut all_boxes: Vec<Vec<(&str, u8)>> = vec![vec![]; NUM_BOXES];
+        for (label, focal_length) in self.steps.iter() {
+            let lense_box = &mut all_boxes[HashCreator::get_hash(label.as_bytes()) as usize];
+            let position = lense_box.iter().position(|(l, _)| l == label);
+            match (focal_length, position) {
+                (Some(focal_length), Some(position)) => lense_box[position].1 = *focal_length,
+                (Some(focal_length), None) => lense_box.push((label, *focal_length)),
+                (None, Some(position)) => {
+                    lense_box.remove(position);
+                }
+                (None, None) => (),
+            }
+        }
+
+        all_boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_index, lense_box)| {
+                lense_box
+                    .iter()
+                    .enumerate()
+                    .map(move |(lense_index, (_, focal_length))| {
+                        (box_index + 1) * (lense_index + 1) * *focal_length as usize
+                    })
+            })
+            .sum()
+    }
+}
+
+fn generate_labels(size: &StepsSize, rng: &mut StdRng) -> Vec<String> {
+    let mut labels = HashSet::new();
+    while labels.len() < size.num_labels {
+        let length = rng.gen_range(1..=size.max_label_length);
+        let label: String = (0..length)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect();
+        labels.insert(label);
+    }
+    let mut labels: Vec<String> = labels.into_iter().collect();
+    labels.sort();
+    labels
+}
+
+pub fn generate_steps(size: &StepsSize, rng: &mut StdRng) -> Steps {
+    let labels = generate_labels(size, rng);
+    let steps: Vec<(String, Option<u8>)> = (0..size.num_steps)
+        .map(|_| {
+            let label = labels[rng.gen_range(0..labels.len())].clone();
+            match rng.gen_bool(size.remove_share) {
+                true => (label, None),
+                false => (label, Some(rng.gen_range(1..=9))),
+            }
+        })
+        .collect();
+
+    let text = steps
+        .iter()
+        .map(|(label, focal_length)| match focal_length {
+            Some(focal_length) => format!("{label}={focal_length}"),
+            None => format!("{label}-"),
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    Steps { text, steps }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random initialization sequence instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let steps = generate_steps(&StepsSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, &steps.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "expected sum {} and focusing power {}",
+        steps.get_hash_sum(),
+        steps.get_focusing_power()
+    );
+    true
+}
@@ -0,0 +1,27 @@
+// streaming HASH algorithm: for every byte, add it, multiply by 17, keep the remainder of 256
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HashCreator {
+    current_value: u8,
+}
+
+impl HashCreator {
+    pub fn create() -> HashCreator {
+        HashCreator { current_value: 0 }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.current_value = self.current_value.wrapping_add(*byte).wrapping_mul(17);
+        }
+    }
+
+    pub fn get_value(&self) -> u8 {
+        self.current_value
+    }
+
+    pub fn get_hash(bytes: &[u8]) -> u8 {
+        let mut hash_creator = HashCreator::create();
+        hash_creator.update(bytes);
+        hash_creator.get_value()
+    }
+}
@@ -0,0 +1,72 @@
+mod lense_box;
+mod vec_deque_box;
+
+use std::hash::Hash;
+
+use crate::hash::HashCreator;
+pub use lense_box::Box;
+pub use vec_deque_box::VecDequeBox;
+
+pub const NUM_BOXES: usize = 256;
+
+// HASHMAP: keys are spread over 256 boxes by their HASH, every box keeps insertion order
+#[derive(Clone, Debug)]
+pub struct HolidayMap<K, V> {
+    boxes: Vec<Box<K, V>>,
+}
+
+impl<K: AsRef<[u8]> + Clone + Eq + Hash, V> HolidayMap<K, V> {
+    pub fn create_empty() -> HolidayMap<K, V> {
+        HolidayMap {
+            boxes: (0..NUM_BOXES).map(|_| Box::create_empty()).collect(),
+        }
+    }
+
+    pub fn get_box_index(key: &K) -> usize {
+        HashCreator::get_hash(key.as_ref()) as usize
+    }
+
+    // returns the replaced value, an existing key keeps its position
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let box_index = HolidayMap::<K, V>::get_box_index(&key);
+        self.boxes[box_index].insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let box_index = HolidayMap::<K, V>::get_box_index(key);
+        self.boxes[box_index].remove(key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.boxes[HolidayMap::<K, V>::get_box_index(key)].get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.boxes[HolidayMap::<K, V>::get_box_index(key)].get_mut(key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.boxes[HolidayMap::<K, V>::get_box_index(key)].contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.boxes.iter().map(|b| b.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.iter().all(|b| b.is_empty())
+    }
+
+    pub fn get_boxes(&self) -> &[Box<K, V>] {
+        &self.boxes
+    }
+
+    // (box_index, slot_index, key, value) in box order, then insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K, &V)> {
+        self.boxes.iter().enumerate().flat_map(|(box_index, b)| {
+            b.iter()
+                .enumerate()
+                .map(move |(slot_index, (key, value))| (box_index, slot_index, key, value))
+        })
+    }
+}
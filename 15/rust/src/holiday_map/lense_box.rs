@@ -0,0 +1,119 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Clone, Debug)]
+struct Slot<K, V> {
+    key: K,
+    value: V,
+    previous: Option<usize>,
+    next: Option<usize>,
+}
+
+// doubly linked list inside a slot vector, so insert, update and remove never shift entries
+#[derive(Clone, Debug)]
+pub struct Box<K, V> {
+    slots: Vec<Option<Slot<K, V>>>,
+    free_slots: Vec<usize>,
+    first: Option<usize>,
+    last: Option<usize>,
+    entries_present: HashMap<K, usize>, // maps key to slot_index
+}
+
+impl<K: Clone + Eq + Hash, V> Box<K, V> {
+    pub fn create_empty() -> Box<K, V> {
+        Box {
+            slots: vec![],
+            free_slots: vec![],
+            first: None,
+            last: None,
+            entries_present: HashMap::new(),
+        }
+    }
+
+    fn get_slot_mut(&mut self, slot_index: usize) -> &mut Slot<K, V> {
+        self.slots[slot_index]
+            .as_mut()
+            .expect("linked slots should be occupied")
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(existing_value) = self.get_mut(&key) {
+            // alrdy existing entry, update value in place
+            return Some(std::mem::replace(existing_value, value));
+        }
+
+        let slot = Slot {
+            key: key.clone(),
+            value,
+            previous: self.last,
+            next: None,
+        };
+        let slot_index = match self.free_slots.pop() {
+            Some(slot_index) => {
+                self.slots[slot_index] = Some(slot);
+                slot_index
+            }
+            None => {
+                self.slots.push(Some(slot));
+                self.slots.len() - 1
+            }
+        };
+
+        match self.last {
+            Some(last_index) => self.get_slot_mut(last_index).next = Some(slot_index),
+            None => self.first = Some(slot_index),
+        }
+        self.last = Some(slot_index);
+        self.entries_present.insert(key, slot_index);
+        None
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        // not existing entry, do nothing
+        let slot_index = self.entries_present.remove(key)?;
+        let slot = self.slots[slot_index]
+            .take()
+            .expect("present keys should be occupied");
+        self.free_slots.push(slot_index);
+
+        match slot.previous {
+            Some(previous_index) => self.get_slot_mut(previous_index).next = slot.next,
+            None => self.first = slot.next,
+        }
+        match slot.next {
+            Some(next_index) => self.get_slot_mut(next_index).previous = slot.previous,
+            None => self.last = slot.previous,
+        }
+        Some(slot.value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let slot_index = *self.entries_present.get(key)?;
+        self.slots[slot_index].as_ref().map(|slot| &slot.value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot_index = *self.entries_present.get(key)?;
+        Some(&mut self.get_slot_mut(slot_index).value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries_present.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries_present.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries_present.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut current = self.first;
+        std::iter::from_fn(move || {
+            let slot = self.slots[current?].as_ref()?;
+            current = slot.next;
+            Some((&slot.key, &slot.value))
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+// previous Box, removal shifts all following entries; kept as baseline for the benchmark
+#[derive(Clone, Debug)]
+pub struct VecDequeBox<K, V> {
+    entries: VecDeque<(K, V)>,
+    entries_present: HashMap<K, usize>, // maps key to pos_in_box
+}
+
+impl<K: Clone + Eq + Hash, V> VecDequeBox<K, V> {
+    pub fn create_empty() -> VecDequeBox<K, V> {
+        VecDequeBox {
+            entries: VecDeque::new(),
+            entries_present: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entries_present.get(&key) {
+            Some(&entry_index) => {
+                // alrdy existing entry, update value in place
+                Some(std::mem::replace(&mut self.entries[entry_index].1, value))
+            }
+            None => {
+                self.entries_present.insert(key.clone(), self.entries.len());
+                self.entries.push_back((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        // not existing entry, do nothing
+        let entry_index = self.entries_present.remove(key)?;
+        let (_, value) = self.entries.remove(entry_index)?;
+
+        // decrement the indices of all following entries
+        for (_, index) in self.entries_present.iter_mut() {
+            if *index > entry_index {
+                *index -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
@@ -1,7 +1,21 @@
+mod benchmark;
+mod generator;
+mod hash;
+mod holiday_map;
 mod part_1;
 mod part_2;
 
+// `--input=PATH` runs another initialization sequence
+fn get_input_path(default: &str) -> String {
+    std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--input=")?.to_string()))
+        .unwrap_or(default.to_string())
+}
+
 fn main() {
+    if generator::main() || benchmark::main() {
+        return;
+    }
     part_1::main();
     part_2::main();
 }
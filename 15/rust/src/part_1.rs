@@ -1,4 +1,7 @@
-use std::{collections::VecDeque, fs};
+use std::fs;
+
+use crate::get_input_path;
+use crate::hash::HashCreator;
 
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
@@ -7,30 +10,16 @@ fn read_text(file_path: String) -> String {
 
 #[derive(Clone, Debug)]
 pub struct InitializationSequence {
-    chars: VecDeque<char>,
+    step: String,
 }
 
 impl InitializationSequence {
     pub fn create(line: String) -> InitializationSequence {
-        InitializationSequence {
-            chars: line.chars().into_iter().collect(),
-        }
+        InitializationSequence { step: line }
     }
 
     fn get_hash(&self) -> u128 {
-        InitializationSequence::hash(0, self.chars.clone())
-    }
-
-    fn hash(current_value: u128, mut chars: VecDeque<char>) -> u128 {
-        if chars.is_empty() {
-            return current_value;
-        }
-
-        let current_char = chars.pop_front().unwrap();
-        let current_char_acsi_num = (current_char as u8) as u128;
-        let new_value = (current_value + current_char_acsi_num) * 17 % 256;
-
-        InitializationSequence::hash(new_value, chars)
+        HashCreator::get_hash(self.step.as_bytes()) as u128
     }
 }
 
@@ -38,14 +27,14 @@ fn parse_input(input: &String) -> Vec<InitializationSequence> {
     input
         .split(",")
         .into_iter()
-        .map(|line| InitializationSequence::create(line.to_string()))
+        .map(|line| InitializationSequence::create(line.replace(['\r', '\n'], "")))
         .collect()
 }
 
 pub fn main() {
     println!("PART 1 ------------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
     let initialization_sequence = parse_input(&input);
 
     let sum: u128 = initialization_sequence
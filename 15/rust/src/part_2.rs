@@ -1,50 +1,42 @@
-mod hash;
 mod lense;
-mod lense_box;
+mod trace;
 
-use lense::Lense;
-use lense_box::Box;
+use crate::get_input_path;
+use crate::holiday_map::{Box, HolidayMap};
+use lense::{Lense, StepError};
 use std::fs;
 
+const TRACE_FILE_PATH: &str = "trace_output.jsonl";
+const SNAPSHOT_INTERVAL: usize = 500;
+
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
     return contents;
 }
 
-fn parse_input(input: &String) -> Vec<Lense> {
-    input
-        .split(",")
-        .into_iter()
-        .map(|line| Lense::create(line.to_string()))
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Lense>, StepError> {
+    Lense::parse_sequence(input)
 }
 
-fn print_all_boxes(all_boxes: &Vec<Box>) {
+fn print_all_boxes(all_boxes: &[Box<String, u8>]) {
     for (i, b) in all_boxes.iter().enumerate() {
-        if b.lenses.is_empty() {
+        if b.is_empty() {
             continue;
         }
 
         print!("Box {i}:");
-        for lense in b.lenses.iter() {
-            lense.print()
+        for (label, focal_length) in b.iter() {
+            print!(" [{label} {focal_length}]");
         }
         print!("\n");
     }
 }
 
-fn calculate_total_focusing_power(all_boxes: Vec<Box>) -> u128 {
+fn calculate_total_focusing_power(all_boxes: &HolidayMap<String, u8>) -> u128 {
     all_boxes
-        .into_iter()
-        .enumerate()
-        .map(|(box_index, b)| {
-            b.lenses
-                .into_iter()
-                .enumerate()
-                .map(|(lense_index, lense)| {
-                    (box_index + 1) as u128 * (lense_index + 1) as u128 * lense.focal_length as u128
-                })
-                .sum::<u128>()
+        .iter()
+        .map(|(box_index, lense_index, _, focal_length)| {
+            (box_index + 1) as u128 * (lense_index + 1) as u128 * *focal_length as u128
         })
         .sum()
 }
@@ -52,20 +44,55 @@ fn calculate_total_focusing_power(all_boxes: Vec<Box>) -> u128 {
 pub fn main() {
     println!("PART 2 ------------");
 
-    let input = read_text("..\\Data\\input_1.txt".to_string());
-    let all_lenses = parse_input(&input);
-
-    // println!("{:?}", all_lenses);
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
+    let all_lenses = match parse_input(&input) {
+        Ok(all_lenses) => all_lenses,
+        Err(error) => {
+            println!("invalid initialization sequence, {error}");
+            return;
+        }
+    };
 
-    let mut all_boxes: Vec<Box> = (0..256).into_iter().map(|_| Box::create_empty()).collect();
+    let mut all_boxes: HolidayMap<String, u8> = HolidayMap::create_empty();
 
-    for lense in all_lenses {
-        let box_index = lense.hash as usize;
-        all_boxes[box_index].handle_lense(lense);
+    for lense in all_lenses.iter() {
+        trace::handle_lense(&mut all_boxes, lense);
     }
 
-    print_all_boxes(&all_boxes);
+    match all_boxes.is_empty() {
+        true => println!("all boxes are empty"),
+        false => print_all_boxes(all_boxes.get_boxes()),
+    }
+    println!("{} lenses in the boxes", all_boxes.len());
 
-    let total_focusing_power = calculate_total_focusing_power(all_boxes);
+    let total_focusing_power = calculate_total_focusing_power(&all_boxes);
     println!("total_focusing_power = {total_focusing_power}");
+
+    if std::env::args().any(|arg| arg == "--trace") {
+        export_trace(&all_lenses, total_focusing_power);
+    }
+}
+
+// `--trace` writes every step to TRACE_FILE_PATH and checks that the snapshots replay
+fn export_trace(all_lenses: &[Lense], total_focusing_power: u128) {
+    let all_steps = trace::trace(all_lenses, SNAPSHOT_INTERVAL);
+    trace::export_json_lines(&all_steps, TRACE_FILE_PATH).expect("trace should be writable");
+
+    let imported_steps =
+        trace::import_json_lines(TRACE_FILE_PATH).expect("trace should be readable");
+    let num_reproduced = imported_steps
+        .iter()
+        .filter_map(|step| Some((step.step_index, step.snapshot.as_ref()?)))
+        .filter(|(step_index, snapshot)| {
+            trace::replay_from(snapshot, *step_index, all_lenses) == total_focusing_power
+        })
+        .count();
+    let num_snapshots = imported_steps
+        .iter()
+        .filter(|step| step.snapshot.is_some())
+        .count();
+    println!(
+        "trace of {} steps written to {TRACE_FILE_PATH}, {num_reproduced} / {num_snapshots} snapshots reproduce the focusing power",
+        imported_steps.len()
+    );
 }
@@ -1,54 +1,135 @@
-use super::hash::HashCreator;
+use std::fmt;
 
 #[derive(Clone, Debug)]
 pub struct Lense {
     pub do_insert: bool,
-    pub hash: u128,
     pub label: String,
     pub focal_length: u8,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StepErrorKind {
+    MissingLabel,
+    MissingOperation,
+    UnexpectedCharacter(char),
+    InvalidFocalLength(Option<char>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepError {
+    pub step_index: usize,
+    pub offset: usize, // character offset in the whole sequence
+    pub kind: StepErrorKind,
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            StepErrorKind::MissingLabel => "expected a label of lowercase letters".to_string(),
+            StepErrorKind::MissingOperation => "expected '=' or '-' after the label".to_string(),
+            StepErrorKind::UnexpectedCharacter(c) => format!("unexpected character {c:?}"),
+            StepErrorKind::InvalidFocalLength(Some(c)) => {
+                format!("expected a focal length from 1 to 9, found {c:?}")
+            }
+            StepErrorKind::InvalidFocalLength(None) => {
+                "expected a focal length from 1 to 9".to_string()
+            }
+        };
+        write!(
+            f,
+            "step {} at offset {}: {reason}",
+            self.step_index, self.offset
+        )
+    }
+}
+
+impl std::error::Error for StepError {}
+
 impl Lense {
-    pub fn create(line: String) -> Lense {
-        match line.contains("-") {
-            true => Lense::create_removing_lense(line),
-            false => Lense::create_adding_lense(line),
+    // step is `label=digit` or `label-`, given as (offset, char) pairs without newlines
+    pub fn parse(
+        step: &[(usize, char)],
+        step_index: usize,
+        step_offset: usize,
+    ) -> Result<Lense, StepError> {
+        let error_at = |position: usize, kind: StepErrorKind| {
+            let offset = match step.get(position) {
+                Some((offset, _)) => *offset,
+                None => step.last().map_or(step_offset, |(offset, _)| offset + 1),
+            };
+            StepError {
+                step_index,
+                offset,
+                kind,
+            }
+        };
+
+        let label: String = step
+            .iter()
+            .map(|(_, c)| *c)
+            .take_while(|c| c.is_ascii_lowercase())
+            .collect();
+        if label.is_empty() {
+            let kind = match step.first() {
+                Some((_, c)) if *c != '=' && *c != '-' => StepErrorKind::UnexpectedCharacter(*c),
+                _ => StepErrorKind::MissingLabel,
+            };
+            return Err(error_at(0, kind));
         }
-    }
 
-    pub fn create_removing_lense(line: String) -> Lense {
-        let mut split = line.split("-");
+        let mut position = label.len();
+        let (do_insert, focal_length) = match step.get(position) {
+            Some((_, '-')) => (false, 0),
+            Some((_, '=')) => {
+                position += 1;
+                match step.get(position) {
+                    Some((_, c @ '1'..='9')) => (true, *c as u8 - b'0'),
+                    Some((_, c)) => {
+                        return Err(error_at(
+                            position,
+                            StepErrorKind::InvalidFocalLength(Some(*c)),
+                        ))
+                    }
+                    None => {
+                        return Err(error_at(position, StepErrorKind::InvalidFocalLength(None)))
+                    }
+                }
+            }
+            Some((_, c)) => return Err(error_at(position, StepErrorKind::UnexpectedCharacter(*c))),
+            None => return Err(error_at(position, StepErrorKind::MissingOperation)),
+        };
 
-        let label = split.next().unwrap().to_string();
-        Lense {
-            do_insert: false,
-            hash: HashCreator::get_hash(&label),
-            label,
-            focal_length: 0,
+        position += 1;
+        if let Some((_, c)) = step.get(position) {
+            return Err(error_at(position, StepErrorKind::UnexpectedCharacter(*c)));
         }
-    }
 
-    pub fn create_adding_lense(line: String) -> Lense {
-        let mut split = line.split("=");
-
-        let label = split.next().unwrap().to_string();
-        let focal_length: u8 = split
-            .next()
-            .unwrap()
-            .to_string()
-            .trim()
-            .parse()
-            .expect("please give me correct focal length!");
-
-        Lense {
-            do_insert: true,
-            hash: HashCreator::get_hash(&label),
+        Ok(Lense {
+            do_insert,
             label,
             focal_length,
-        }
+        })
     }
 
-    pub fn print(&self) {
-        print!(" [{0} {1}]", self.label, self.focal_length)
+    // newlines are ignored, every other character belongs to a step
+    pub fn parse_sequence(input: &str) -> Result<Vec<Lense>, StepError> {
+        let mut all_lenses = vec![];
+        let mut step: Vec<(usize, char)> = vec![];
+        let mut step_offset = 0;
+
+        for (offset, c) in input.chars().enumerate() {
+            match c {
+                '\r' | '\n' => (),
+                ',' => {
+                    all_lenses.push(Lense::parse(&step, all_lenses.len(), step_offset)?);
+                    step.clear();
+                    step_offset = offset + 1;
+                }
+                _ => step.push((offset, c)),
+            }
+        }
+        all_lenses.push(Lense::parse(&step, all_lenses.len(), step_offset)?);
+
+        Ok(all_lenses)
     }
 }
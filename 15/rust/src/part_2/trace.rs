@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+};
+
+use super::{calculate_total_focusing_power, lense::Lense};
+use crate::{hash::HashCreator, holiday_map::HolidayMap};
+
+// every non-empty box as (box_index, [(label, focal_length)]) in lense order
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub boxes: Vec<(usize, Vec<(String, u8)>)>,
+}
+
+impl Snapshot {
+    pub fn create(all_boxes: &HolidayMap<String, u8>) -> Snapshot {
+        let boxes = all_boxes
+            .get_boxes()
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_empty())
+            .map(|(box_index, b)| {
+                let lenses = b
+                    .iter()
+                    .map(|(label, focal_length)| (label.clone(), *focal_length))
+                    .collect();
+                (box_index, lenses)
+            })
+            .collect();
+
+        Snapshot { boxes }
+    }
+
+    pub fn restore(&self) -> HolidayMap<String, u8> {
+        let mut all_boxes = HolidayMap::create_empty();
+        for (_, lenses) in self.boxes.iter() {
+            for (label, focal_length) in lenses {
+                all_boxes.insert(label.clone(), *focal_length);
+            }
+        }
+        all_boxes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub step_index: usize,
+    pub label: String,
+    pub hash: u8,
+    pub operation: char,
+    pub focal_length: Option<u8>,
+    pub box_contents: Vec<(String, u8)>, // the touched box after the step
+    pub snapshot: Option<Snapshot>,      // the whole state after the step
+}
+
+pub fn handle_lense(all_boxes: &mut HolidayMap<String, u8>, lense: &Lense) {
+    match lense.do_insert {
+        true => match all_boxes.get_mut(&lense.label) {
+            // the new lense takes the place of the old one
+            Some(focal_length) => *focal_length = lense.focal_length,
+            None => {
+                all_boxes.insert(lense.label.clone(), lense.focal_length);
+            }
+        },
+        // without a lense of that label nothing moves
+        false if all_boxes.contains_key(&lense.label) => {
+            all_boxes.remove(&lense.label);
+        }
+        false => (),
+    }
+}
+
+// applies all lenses and records every step, with a full snapshot every snapshot_interval steps
+pub fn trace(all_lenses: &[Lense], snapshot_interval: usize) -> Vec<TraceStep> {
+    let mut all_boxes: HolidayMap<String, u8> = HolidayMap::create_empty();
+
+    all_lenses
+        .iter()
+        .enumerate()
+        .map(|(step_index, lense)| {
+            handle_lense(&mut all_boxes, lense);
+
+            let hash = HashCreator::get_hash(lense.label.as_bytes());
+            let box_contents = all_boxes.get_boxes()[hash as usize]
+                .iter()
+                .map(|(label, focal_length)| (label.clone(), *focal_length))
+                .collect();
+            let is_snapshot_step =
+                (step_index + 1) % snapshot_interval == 0 || step_index + 1 == all_lenses.len();
+
+            TraceStep {
+                step_index,
+                label: lense.label.clone(),
+                hash,
+                operation: if lense.do_insert { '=' } else { '-' },
+                focal_length: all_boxes.get(&lense.label).copied(),
+                box_contents,
+                snapshot: is_snapshot_step.then(|| Snapshot::create(&all_boxes)),
+            }
+        })
+        .collect()
+}
+
+// continues the sequence from the snapshot taken after step_index
+pub fn replay_from(snapshot: &Snapshot, step_index: usize, all_lenses: &[Lense]) -> u128 {
+    let mut all_boxes = snapshot.restore();
+    for lense in all_lenses.iter().skip(step_index + 1) {
+        handle_lense(&mut all_boxes, lense);
+    }
+    calculate_total_focusing_power(&all_boxes)
+}
+
+pub fn export_json_lines(trace: &[TraceStep], file_path: &str) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(file_path)?);
+    for step in trace {
+        serde_json::to_writer(&mut writer, step)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+pub fn import_json_lines(file_path: &str) -> std::io::Result<Vec<TraceStep>> {
+    BufReader::new(File::open(file_path)?)
+        .lines()
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
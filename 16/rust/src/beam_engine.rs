@@ -0,0 +1,371 @@
+use std::collections::VecDeque;
+
+pub const ALL_DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn get_symbol(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    pub fn get_bit(&self) -> u8 {
+        match self {
+            Direction::Up => 1,
+            Direction::Down => 2,
+            Direction::Left => 4,
+            Direction::Right => 8,
+        }
+    }
+
+    fn get_index(&self) -> usize {
+        self.get_bit().trailing_zeros() as usize
+    }
+}
+
+// directions a beam leaves a tile with, when it enters the tile moving in direction
+pub fn get_outgoing(tile: char, direction: Direction) -> Vec<Direction> {
+    use Direction::*;
+
+    match (tile, direction) {
+        ('/', Right) => vec![Up],
+        ('/', Left) => vec![Down],
+        ('/', Up) => vec![Right],
+        ('/', Down) => vec![Left],
+        ('\\', Right) => vec![Down],
+        ('\\', Left) => vec![Up],
+        ('\\', Up) => vec![Left],
+        ('\\', Down) => vec![Right],
+        ('|', Left | Right) => vec![Up, Down],
+        ('-', Up | Down) => vec![Left, Right],
+        _ => vec![direction],
+    }
+}
+
+pub fn is_optical_element(tile: char) -> bool {
+    matches!(tile, '/' | '\\' | '|' | '-')
+}
+
+#[derive(Clone, Debug)]
+pub struct Contraption {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<char>, // row major
+}
+
+impl Contraption {
+    pub fn create(layout: &[Vec<char>]) -> Contraption {
+        let rows: Vec<Vec<char>> = layout
+            .iter()
+            .map(|line| line.iter().copied().filter(|c| *c != '\r').collect())
+            .filter(|line: &Vec<char>| !line.is_empty())
+            .collect();
+
+        Contraption {
+            width: rows.first().map_or(0, |line| line.len()),
+            height: rows.len(),
+            tiles: rows.concat(),
+        }
+    }
+
+    pub fn get_tile(&self, cell: usize) -> char {
+        self.tiles[cell]
+    }
+
+    pub fn get_neighbour(&self, cell: usize, direction: Direction) -> Option<usize> {
+        let (y, x) = (cell / self.width, cell % self.width);
+        match direction {
+            Direction::Up if y > 0 => Some(cell - self.width),
+            Direction::Down if y + 1 < self.height => Some(cell + self.width),
+            Direction::Left if x > 0 => Some(cell - 1),
+            Direction::Right if x + 1 < self.width => Some(cell + 1),
+            _ => None,
+        }
+    }
+
+    // every beam entering from the border, as (y, x, direction)
+    pub fn get_edge_starts(&self) -> Vec<(usize, usize, Direction)> {
+        let mut edge_starts = vec![];
+        for x in 0..self.width {
+            edge_starts.push((0, x, Direction::Down));
+            edge_starts.push((self.height - 1, x, Direction::Up));
+        }
+        for y in 0..self.height {
+            edge_starts.push((y, 0, Direction::Right));
+            edge_starts.push((y, self.width - 1, Direction::Left));
+        }
+        edge_starts
+    }
+
+    // direction bitmask per cell of all beams that passed through it, starts outside are ignored
+    pub fn simulate(&self, starts: &[(usize, usize, Direction)]) -> Vec<u8> {
+        let mut visited = vec![0_u8; self.tiles.len()];
+        let mut queued_moves: VecDeque<(usize, Direction)> = starts
+            .iter()
+            .filter(|(y, x, _)| *y < self.height && *x < self.width)
+            .map(|(y, x, direction)| (y * self.width + x, *direction))
+            .collect();
+
+        while let Some((cell, direction)) = queued_moves.pop_front() {
+            if visited[cell] & direction.get_bit() != 0 {
+                //alrdy travelled to -> stop loop
+                continue;
+            }
+            visited[cell] |= direction.get_bit();
+
+            for next_direction in get_outgoing(self.get_tile(cell), direction) {
+                if let Some(next_cell) = self.get_neighbour(cell, next_direction) {
+                    queued_moves.push_back((next_cell, next_direction));
+                }
+            }
+        }
+        visited
+    }
+
+    pub fn count_energized(visited: &[u8]) -> usize {
+        visited
+            .iter()
+            .filter(|directions| **directions != 0)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Bitset {
+    chunks: Vec<u64>,
+}
+
+impl Bitset {
+    fn create_empty(num_bits: usize) -> Bitset {
+        Bitset {
+            chunks: vec![0; num_bits.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, bit: usize) {
+        self.chunks[bit / 64] |= 1 << (bit % 64);
+    }
+
+    fn union_with(&mut self, other: &Bitset) {
+        for (chunk, other_chunk) in self.chunks.iter_mut().zip(other.chunks.iter()) {
+            *chunk |= other_chunk;
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.chunks
+            .iter()
+            .map(|chunk| chunk.count_ones() as usize)
+            .sum()
+    }
+}
+
+// a beam leaving an optical element, the cells until the next element are its segment
+#[derive(Clone, Debug)]
+struct BeamNode {
+    segment: Vec<usize>, // cells from the neighbour up to and including the next element
+    successors: Vec<usize>,
+}
+
+// energized cells of every beam leaving an optical element, shared by all starts
+#[derive(Clone, Debug)]
+pub struct BeamEngine {
+    contraption: Contraption,
+    node_indices: Vec<Option<usize>>, // (cell * 4 + direction) -> node index
+    scc_of_node: Vec<usize>,
+    energized_per_scc: Vec<Bitset>,
+}
+
+impl BeamEngine {
+    pub fn create(contraption: Contraption) -> BeamEngine {
+        let mut node_indices = vec![None; contraption.tiles.len() * 4];
+        let mut num_nodes = 0;
+        for cell in 0..contraption.tiles.len() {
+            if !is_optical_element(contraption.get_tile(cell)) {
+                continue;
+            }
+            for direction in ALL_DIRECTIONS {
+                node_indices[cell * 4 + direction.get_index()] = Some(num_nodes);
+                num_nodes += 1;
+            }
+        }
+
+        let mut all_nodes: Vec<BeamNode> = Vec::with_capacity(num_nodes);
+        for cell in 0..contraption.tiles.len() {
+            if !is_optical_element(contraption.get_tile(cell)) {
+                continue;
+            }
+            for direction in ALL_DIRECTIONS {
+                let (segment, successors) =
+                    BeamEngine::follow_beam(&contraption, &node_indices, cell, direction);
+                all_nodes.push(BeamNode {
+                    segment,
+                    successors,
+                });
+            }
+        }
+
+        let (scc_of_node, energized_per_scc) =
+            BeamEngine::get_energized_per_scc(&all_nodes, &contraption);
+        BeamEngine {
+            contraption,
+            node_indices,
+            scc_of_node,
+            energized_per_scc,
+        }
+    }
+
+    // walks from the neighbour of cell until the next optical element or the border
+    fn follow_beam(
+        contraption: &Contraption,
+        node_indices: &[Option<usize>],
+        cell: usize,
+        direction: Direction,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let mut segment = vec![];
+        let mut current = contraption.get_neighbour(cell, direction);
+        while let Some(current_cell) = current {
+            segment.push(current_cell);
+            let tile = contraption.get_tile(current_cell);
+            if is_optical_element(tile) {
+                let successors = get_outgoing(tile, direction)
+                    .into_iter()
+                    .filter_map(|next_direction| {
+                        node_indices[current_cell * 4 + next_direction.get_index()]
+                    })
+                    .collect();
+                return (segment, successors);
+            }
+            current = contraption.get_neighbour(current_cell, direction);
+        }
+        (segment, vec![])
+    }
+
+    // tarjan, iterative: scc's are completed in reverse topological order,
+    // so every successor scc is already united when a scc is finished
+    fn get_energized_per_scc(
+        all_nodes: &[BeamNode],
+        contraption: &Contraption,
+    ) -> (Vec<usize>, Vec<Bitset>) {
+        let num_nodes = all_nodes.len();
+        let mut order = vec![usize::MAX; num_nodes];
+        let mut low_link = vec![0; num_nodes];
+        let mut is_on_stack = vec![false; num_nodes];
+        let mut scc_of_node = vec![usize::MAX; num_nodes];
+        let mut scc_stack: Vec<usize> = vec![];
+        let mut energized_per_scc: Vec<Bitset> = vec![];
+        let mut next_order = 0;
+
+        for root in 0..num_nodes {
+            if order[root] != usize::MAX {
+                continue;
+            }
+
+            let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+            while let Some((node, successor_position)) = call_stack.pop() {
+                if successor_position == 0 {
+                    order[node] = next_order;
+                    low_link[node] = next_order;
+                    next_order += 1;
+                    scc_stack.push(node);
+                    is_on_stack[node] = true;
+                }
+
+                if let Some(&successor) = all_nodes[node].successors.get(successor_position) {
+                    call_stack.push((node, successor_position + 1));
+                    if order[successor] == usize::MAX {
+                        call_stack.push((successor, 0));
+                    } else if is_on_stack[successor] {
+                        low_link[node] = low_link[node].min(order[successor]);
+                    }
+                    continue;
+                }
+
+                // all successors done, hand the low link to the caller
+                if let Some((caller, _)) = call_stack.last() {
+                    low_link[*caller] = low_link[*caller].min(low_link[node]);
+                }
+                if low_link[node] != order[node] {
+                    continue;
+                }
+
+                let scc_index = energized_per_scc.len();
+                let mut energized = Bitset::create_empty(contraption.tiles.len());
+                let mut members = vec![];
+                loop {
+                    let member = scc_stack.pop().unwrap();
+                    is_on_stack[member] = false;
+                    scc_of_node[member] = scc_index;
+                    members.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                for member in members {
+                    for cell in all_nodes[member].segment.iter() {
+                        energized.insert(*cell);
+                    }
+                    for successor in all_nodes[member].successors.iter() {
+                        let successor_scc = scc_of_node[*successor];
+                        if successor_scc != scc_index {
+                            energized.union_with(&energized_per_scc[successor_scc]);
+                        }
+                    }
+                }
+                energized_per_scc.push(energized);
+            }
+        }
+
+        (scc_of_node, energized_per_scc)
+    }
+
+    pub fn get_energy(&self, start: (usize, usize, Direction)) -> usize {
+        let (y, x, direction) = start;
+        let start_cell = y * self.contraption.width + x;
+
+        // walk to the first optical element, the start cell itself included
+        let mut energized = Bitset::create_empty(self.contraption.tiles.len());
+        let mut current = Some(start_cell);
+        while let Some(cell) = current {
+            energized.insert(cell);
+            let tile = self.contraption.get_tile(cell);
+            if is_optical_element(tile) {
+                for next_direction in get_outgoing(tile, direction) {
+                    if let Some(node_index) =
+                        self.node_indices[cell * 4 + next_direction.get_index()]
+                    {
+                        energized.union_with(&self.energized_per_scc[self.scc_of_node[node_index]]);
+                    }
+                }
+                break;
+            }
+            current = self.contraption.get_neighbour(cell, direction);
+        }
+        energized.count()
+    }
+
+    pub fn get_max_energy_from_edges(&self) -> usize {
+        self.contraption
+            .get_edge_starts()
+            .into_iter()
+            .map(|start| self.get_energy(start))
+            .max()
+            .unwrap_or(0)
+    }
+}
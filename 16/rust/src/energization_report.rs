@@ -0,0 +1,130 @@
+use crate::beam_engine::{
+    get_outgoing, is_optical_element, Contraption, Direction, ALL_DIRECTIONS,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementUse {
+    Unused,
+    PassedThrough, // splitter only hit end-on, acts like empty space
+    Active,        // deflected or split at least one beam
+}
+
+#[derive(Clone, Debug)]
+pub struct CellReport {
+    pub tile: char,
+    pub directions: Vec<Direction>, // directions beams entered the cell with
+    pub num_beams: usize,           // number of sources whose beam passes the cell
+    pub element_use: Option<ElementUse>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnergizationReport {
+    pub width: usize,
+    pub cells: Vec<CellReport>, // row major
+}
+
+impl EnergizationReport {
+    // every source is traced on its own, so beams can be counted per cell
+    pub fn create(
+        contraption: &Contraption,
+        sources: &[(usize, usize, Direction)],
+    ) -> EnergizationReport {
+        let mut directions_per_cell = vec![0_u8; contraption.tiles.len()];
+        let mut num_beams_per_cell = vec![0; contraption.tiles.len()];
+        for source in sources {
+            let visited = contraption.simulate(&[*source]);
+            for (cell, directions) in visited.into_iter().enumerate() {
+                directions_per_cell[cell] |= directions;
+                num_beams_per_cell[cell] += (directions != 0) as usize;
+            }
+        }
+
+        let cells = (0..contraption.tiles.len())
+            .map(|cell| {
+                let tile = contraption.get_tile(cell);
+                let directions: Vec<Direction> = ALL_DIRECTIONS
+                    .into_iter()
+                    .filter(|direction| directions_per_cell[cell] & direction.get_bit() != 0)
+                    .collect();
+                let element_use = is_optical_element(tile)
+                    .then(|| EnergizationReport::get_element_use(tile, &directions));
+
+                CellReport {
+                    tile,
+                    directions,
+                    num_beams: num_beams_per_cell[cell],
+                    element_use,
+                }
+            })
+            .collect();
+
+        EnergizationReport {
+            width: contraption.width,
+            cells,
+        }
+    }
+
+    fn get_element_use(tile: char, directions: &[Direction]) -> ElementUse {
+        if directions.is_empty() {
+            return ElementUse::Unused;
+        }
+        let does_change_beam = directions
+            .iter()
+            .any(|direction| get_outgoing(tile, *direction) != vec![*direction]);
+        match does_change_beam {
+            true => ElementUse::Active,
+            false => ElementUse::PassedThrough,
+        }
+    }
+
+    pub fn get_num_energized(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|cell| !cell.directions.is_empty())
+            .count()
+    }
+
+    // cell as (y, x) crossed by beams of the most sources, with that number of sources
+    pub fn get_busiest_cell(&self) -> Option<((usize, usize), usize)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, cell)| cell.num_beams)
+            .map(|(index, cell)| ((index / self.width, index % self.width), cell.num_beams))
+    }
+
+    // optical elements as (y, x, tile) that never changed a beam
+    pub fn get_dead_elements(&self) -> Vec<(usize, usize, char)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| {
+                cell.element_use
+                    .is_some_and(|element_use| element_use != ElementUse::Active)
+            })
+            .map(|(index, cell)| (index / self.width, index % self.width, cell.tile))
+            .collect()
+    }
+
+    // elements as their tile, single beams as their direction, crossings as their beam count
+    pub fn render(&self) -> String {
+        self.cells
+            .chunks(self.width)
+            .map(|line| {
+                line.iter()
+                    .map(
+                        |cell| match (cell.element_use, cell.directions.as_slice()) {
+                            (Some(_), _) => cell.tile,
+                            (None, []) => '.',
+                            (None, [direction]) => direction.get_symbol(),
+                            (None, all_directions) => {
+                                char::from_digit(all_directions.len() as u32, 10).unwrap()
+                            }
+                        },
+                    )
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
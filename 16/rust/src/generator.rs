@@ -0,0 +1,65 @@
+use std::fs;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const LINE_ENDING: &str = "\n"; // part 1 and part 2 split the rows on `\n` only
+
+const TILES: [char; 4] = ['/', '\\', '|', '-'];
+
+pub struct ContraptionSize {
+    pub width: usize,
+    pub height: usize,
+    pub tile_density: f64, // share of cells holding a mirror or a splitter
+}
+
+impl ContraptionSize {
+    pub fn create(scale: usize) -> ContraptionSize {
+        ContraptionSize {
+            width: 110 * scale,
+            height: 110 * scale,
+            tile_density: 0.1,
+        }
+    }
+}
+
+pub fn generate_contraption(size: &ContraptionSize, rng: &mut StdRng) -> String {
+    let lines: Vec<String> = (0..size.height)
+        .map(|_| {
+            (0..size.width)
+                .map(|_| match rng.gen_bool(size.tile_density) {
+                    true => *TILES.choose(rng).unwrap(),
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect();
+    lines.join(LINE_ENDING)
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random contraption instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let contraption = generate_contraption(&ContraptionSize::create(scale), &mut rng);
+    fs::write(OUTPUT_FILE, contraption).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    true
+}
@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use crate::beam_engine::{is_optical_element, Contraption, Direction};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Change {
+    Flip,   // '/' <-> '\'
+    Rotate, // '|' <-> '-'
+    Remove,
+}
+
+impl Change {
+    pub fn apply(&self, tile: char) -> Option<char> {
+        match (self, tile) {
+            (Change::Flip, '/') => Some('\\'),
+            (Change::Flip, '\\') => Some('/'),
+            (Change::Rotate, '|') => Some('-'),
+            (Change::Rotate, '-') => Some('|'),
+            (Change::Remove, tile) if is_optical_element(tile) => Some('.'),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Modification {
+    pub y: usize,
+    pub x: usize,
+    pub change: Change,
+    pub from: char,
+    pub to: char,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SearchLimits {
+    pub max_changes: usize,
+    pub beam_width: usize,      // layouts kept after every round
+    pub max_evaluations: usize, // simulations over the whole search
+}
+
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    pub modifications: Vec<Modification>, // sorted by cell
+    pub energy: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Optimizer {
+    contraption: Contraption,
+    start: (usize, usize, Direction),
+    num_evaluations: usize,
+}
+
+impl Optimizer {
+    pub fn create(layout: &[Vec<char>], start: (usize, usize, Direction)) -> Optimizer {
+        Optimizer {
+            contraption: Contraption::create(layout),
+            start,
+            num_evaluations: 0,
+        }
+    }
+
+    // simulations done by the last search
+    pub fn get_num_evaluations(&self) -> usize {
+        self.num_evaluations
+    }
+
+    fn get_modified(&self, modifications: &[Modification]) -> Contraption {
+        let mut contraption = self.contraption.clone();
+        for modification in modifications {
+            contraption.tiles[modification.y * contraption.width + modification.x] =
+                modification.to;
+        }
+        contraption
+    }
+
+    fn evaluate(&mut self, contraption: &Contraption) -> (usize, Vec<u8>) {
+        self.num_evaluations += 1;
+        let visited = contraption.simulate(&[self.start]);
+        (Contraption::count_energized(&visited), visited)
+    }
+
+    // only elements a beam reaches can change the result, untouched cells are skipped
+    fn get_candidates(
+        &self,
+        contraption: &Contraption,
+        visited: &[u8],
+        modifications: &[Modification],
+    ) -> Vec<Modification> {
+        let mut candidates = vec![];
+        for (cell, directions) in visited.iter().enumerate() {
+            let (y, x) = (cell / contraption.width, cell % contraption.width);
+            let tile = contraption.get_tile(cell);
+            if *directions == 0
+                || !is_optical_element(tile)
+                || modifications.iter().any(|m| m.y == y && m.x == x)
+            {
+                continue;
+            }
+            for change in [Change::Flip, Change::Rotate, Change::Remove] {
+                if let Some(to) = change.apply(tile) {
+                    candidates.push(Modification {
+                        y,
+                        x,
+                        change,
+                        from: tile,
+                        to,
+                    });
+                }
+            }
+        }
+        candidates
+    }
+
+    // every single change with its energy difference, biggest gain first
+    pub fn get_sensitivity(&mut self) -> Vec<(Modification, isize)> {
+        self.num_evaluations = 0;
+        let (energy, visited) = self.evaluate(&self.contraption.clone());
+        let candidates = self.get_candidates(&self.contraption, &visited, &[]);
+
+        let mut all_gains: Vec<(Modification, isize)> = candidates
+            .into_iter()
+            .map(|candidate| {
+                let (new_energy, _) = self.evaluate(&self.get_modified(&[candidate]));
+                (candidate, new_energy as isize - energy as isize)
+            })
+            .collect();
+        all_gains.sort_by(|(m_a, gain_a), (m_b, gain_b)| gain_b.cmp(gain_a).then(m_a.cmp(m_b)));
+        all_gains
+    }
+
+    // beam search over sets of up to max_changes modifications,
+    // ties are broken by the modifications themselves so the result is deterministic
+    pub fn find_best_changes(&mut self, limits: SearchLimits) -> Suggestion {
+        self.num_evaluations = 0;
+        let (energy, visited) = self.evaluate(&self.contraption.clone());
+        let mut best = Suggestion {
+            modifications: vec![],
+            energy,
+        };
+        let mut frontier: Vec<(Vec<Modification>, usize, Vec<u8>)> =
+            vec![(vec![], energy, visited)];
+        let mut seen: HashSet<Vec<Modification>> = HashSet::new();
+
+        'rounds: for _ in 0..limits.max_changes {
+            let mut next_frontier = vec![];
+            for (modifications, _, visited) in frontier.iter() {
+                let contraption = self.get_modified(modifications);
+                for candidate in self.get_candidates(&contraption, visited, modifications) {
+                    let mut new_modifications = modifications.clone();
+                    new_modifications.push(candidate);
+                    new_modifications.sort();
+                    if !seen.insert(new_modifications.clone()) {
+                        continue;
+                    }
+                    if self.num_evaluations >= limits.max_evaluations {
+                        break 'rounds;
+                    }
+
+                    let (new_energy, new_visited) =
+                        self.evaluate(&self.get_modified(&new_modifications));
+                    if new_energy > best.energy {
+                        best = Suggestion {
+                            modifications: new_modifications.clone(),
+                            energy: new_energy,
+                        };
+                    }
+                    next_frontier.push((new_modifications, new_energy, new_visited));
+                }
+            }
+
+            next_frontier.sort_by(|(m_a, energy_a, _), (m_b, energy_b, _)| {
+                energy_b.cmp(energy_a).then(m_a.cmp(m_b))
+            });
+            // changes that light the very same beams would fill the beam with duplicates
+            let mut kept_beams: HashSet<Vec<u8>> = HashSet::new();
+            next_frontier.retain(|(_, _, visited)| kept_beams.insert(visited.clone()));
+            next_frontier.truncate(limits.beam_width);
+            if next_frontier.is_empty() {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        best
+    }
+}
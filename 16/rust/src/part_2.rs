@@ -1,4 +1,7 @@
-use std::{collections::VecDeque, fs};
+use std::{collections::VecDeque, fs, time::Instant};
+
+use crate::beam_engine::{BeamEngine, Contraption};
+use crate::get_input_path;
 
 fn read_text(file_path: String) -> String {
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
@@ -20,55 +23,6 @@ fn get_visited_board(layout: &Vec<Vec<char>>) -> Vec<Vec<Vec<char>>> {
         .collect()
 }
 
-pub fn _clear_terminal_screen() {
-    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-
-    // print!("{}[2J", 27 as char);
-
-    // if cfg!(target_os = "windows") {
-    //     Command::new("cmd")
-    //         .args(["/c", "cls"])
-    //         .spawn()
-    //         .expect("cls command failed to start")
-    //         .wait()
-    //         .expect("failed to wait");
-    // } else {
-    //     Command::new("clear")
-    //         .spawn()
-    //         .expect("clear command failed to start")
-    //         .wait()
-    //         .expect("failed to wait");
-    // };
-}
-
-fn _print_board_visited(layout: &Vec<Vec<Vec<char>>>) {
-    println!("------------");
-    for y in 0..layout.len() {
-        for x in 0..layout[y].len() {
-            match layout[y][x].len() {
-                0 => print!("."),
-                1 => print!("{0}", layout[y][x][0]),
-                _ => print!("2"),
-            }
-        }
-        print!("\n");
-    }
-    _clear_terminal_screen();
-}
-
-fn _print_energized(layout: &Vec<Vec<Vec<char>>>) {
-    println!("------------");
-    for y in 0..layout.len() {
-        for x in 0..layout[y].len() {
-            match layout[y][x].len() {
-                0 => print!("."),
-                _ => print!("#"),
-            }
-        }
-        print!("\n");
-    }
-}
-
 fn get_next_pos(
     layout: &Vec<Vec<char>>,
     visited_board: &mut Vec<Vec<Vec<char>>>,
@@ -78,8 +32,6 @@ fn get_next_pos(
     let (y_og, x_og) = pos_moved_on;
     visited_board[y_og][x_og].push(last_symbol);
 
-    // print_board_visited(&visited_board);
-
     let y = y_og as i32;
     let x = x_og as i32;
 
@@ -219,9 +171,6 @@ pub fn solve_and_return_energy_score(
         queued_moves.append(&mut VecDeque::from(next_moves));
     }
 
-    // print_board_visited(&visited_board);
-    // print_energized(&visited_board);
-
     let num_energized = calc_energized(&visited_board);
     num_energized
 }
@@ -230,18 +179,31 @@ pub fn main() {
     use rayon::prelude::*;
 
     println!("PART 2 ------------");
-    let input = read_text("..\\Data\\input_1.txt".to_string());
+    let input = read_text(get_input_path("..\\Data\\input_1.txt"));
 
     let layout: Vec<Vec<char>> = parse_input(input);
-    let all_start_pos = get_start_positions(&layout);
-
-    // println!("{:?}", all_start_pos);
 
-    let max_energy: i32 = all_start_pos
+    let start = Instant::now();
+    let engine = BeamEngine::create(Contraption::create(&layout));
+    let max_energy = engine.get_max_energy_from_edges();
+    println!(
+        "max_energy = {max_energy} ({:.4}s beam engine)",
+        start.elapsed().as_secs_f64()
+    );
+
+    // `--brute-force` cross-checks the engine against the original simulation of every start
+    if !std::env::args().any(|arg| arg == "--brute-force") {
+        return;
+    }
+    let start = Instant::now();
+    let all_start_pos = get_start_positions(&layout);
+    let max_energy_brute_force: i32 = all_start_pos
         .par_iter()
         .map(|start_pos| solve_and_return_energy_score(&layout, start_pos.clone()))
         .max()
         .unwrap();
-
-    println!("max_energy = {max_energy}");
+    println!(
+        "max_energy = {max_energy_brute_force} ({:.4}s parallel brute force)",
+        start.elapsed().as_secs_f64()
+    );
 }
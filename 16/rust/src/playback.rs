@@ -0,0 +1,259 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use crate::beam_engine::{
+    get_outgoing, is_optical_element, Contraption, Direction, ALL_DIRECTIONS,
+};
+use crate::get_input_path;
+
+const FRAMES_FILE_PATH: &str = "frames_output.txt";
+const START_DELAY_MS: u64 = 100;
+const MIN_DELAY_MS: u64 = 10;
+const MAX_DELAY_MS: u64 = 2000;
+
+const COLOR_FRONTIER: &str = "\x1b[1;31m";
+const COLOR_ENERGIZED: &str = "\x1b[33m";
+const COLOR_RESET: &str = "\x1b[0m";
+
+pub fn clear_terminal_screen() {
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+}
+
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub tick: usize,
+    pub visited: Vec<u8>, // direction bitmask per cell, frontier included
+    pub frontier: Vec<(usize, Direction)>, // beams that just entered a cell
+}
+
+impl Frame {
+    // frontier beams as their direction, energized cells as their tile or beam directions
+    pub fn render(&self, contraption: &Contraption, use_color: bool) -> String {
+        let mut frontier_symbols: Vec<Option<char>> = vec![None; contraption.tiles.len()];
+        for (cell, direction) in self.frontier.iter() {
+            frontier_symbols[*cell] = Some(direction.get_symbol());
+        }
+
+        let mut rendered = format!("tick {}\n", self.tick);
+        for y in 0..contraption.height {
+            for x in 0..contraption.width {
+                let cell = y * contraption.width + x;
+                let tile = contraption.get_tile(cell);
+                let directions: Vec<Direction> = ALL_DIRECTIONS
+                    .into_iter()
+                    .filter(|direction| self.visited[cell] & direction.get_bit() != 0)
+                    .collect();
+
+                let (symbol, color) = match (frontier_symbols[cell], directions.as_slice()) {
+                    (Some(symbol), _) if !is_optical_element(tile) => (symbol, COLOR_FRONTIER),
+                    (Some(_), _) => (tile, COLOR_FRONTIER),
+                    (None, []) => (tile, ""),
+                    (None, _) if is_optical_element(tile) => (tile, COLOR_ENERGIZED),
+                    (None, [direction]) => (direction.get_symbol(), COLOR_ENERGIZED),
+                    (None, all_directions) => (
+                        char::from_digit(all_directions.len() as u32, 10).unwrap(),
+                        COLOR_ENERGIZED,
+                    ),
+                };
+
+                match use_color && !color.is_empty() {
+                    true => rendered.push_str(&format!("{color}{symbol}{COLOR_RESET}")),
+                    false => rendered.push(symbol),
+                }
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+// advances all beams by one cell per tick
+pub struct BeamPlayback<'a> {
+    contraption: &'a Contraption,
+    visited: Vec<u8>,
+    frontier: Vec<(usize, Direction)>,
+    tick: usize,
+}
+
+impl<'a> BeamPlayback<'a> {
+    pub fn create(
+        contraption: &'a Contraption,
+        starts: &[(usize, usize, Direction)],
+    ) -> BeamPlayback<'a> {
+        let mut playback = BeamPlayback {
+            contraption,
+            visited: vec![0; contraption.tiles.len()],
+            frontier: vec![],
+            tick: 0,
+        };
+        let frontier = starts
+            .iter()
+            .filter(|(y, x, _)| *y < contraption.height && *x < contraption.width)
+            .map(|(y, x, direction)| (y * contraption.width + x, *direction))
+            .collect();
+        playback.enter(frontier);
+        playback
+    }
+
+    fn enter(&mut self, candidates: Vec<(usize, Direction)>) {
+        self.frontier.clear();
+        for (cell, direction) in candidates {
+            if self.visited[cell] & direction.get_bit() != 0 {
+                //alrdy travelled to -> stop loop
+                continue;
+            }
+            self.visited[cell] |= direction.get_bit();
+            self.frontier.push((cell, direction));
+        }
+    }
+
+    pub fn get_frame(&self) -> Frame {
+        Frame {
+            tick: self.tick,
+            visited: self.visited.clone(),
+            frontier: self.frontier.clone(),
+        }
+    }
+}
+
+impl Iterator for BeamPlayback<'_> {
+    type Item = Frame;
+
+    // yields the current frame, then moves every frontier beam one cell further
+    fn next(&mut self) -> Option<Frame> {
+        if self.frontier.is_empty() {
+            return None;
+        }
+        let frame = self.get_frame();
+
+        let candidates = self
+            .frontier
+            .iter()
+            .flat_map(|(cell, direction)| {
+                get_outgoing(self.contraption.get_tile(*cell), *direction)
+                    .into_iter()
+                    .filter_map(|next_direction| {
+                        let next_cell = self.contraption.get_neighbour(*cell, next_direction)?;
+                        Some((next_cell, next_direction))
+                    })
+            })
+            .collect();
+        self.enter(candidates);
+        self.tick += 1;
+
+        Some(frame)
+    }
+}
+
+pub fn write_frames(
+    frames: &[Frame],
+    contraption: &Contraption,
+    file_path: &str,
+) -> io::Result<()> {
+    let rendered: Vec<String> = frames
+        .iter()
+        .map(|frame| frame.render(contraption, false))
+        .collect();
+    fs::write(file_path, rendered.join("\n"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Command {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+// reads one command per line, so it also works in terminals without raw mode
+fn spawn_command_reader() -> Receiver<Command> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines().map_while(Result::ok) {
+            let command = match line.trim() {
+                "p" => Command::TogglePause,
+                "" | "s" => Command::Step,
+                "+" => Command::Faster,
+                "-" => Command::Slower,
+                "q" => Command::Quit,
+                _ => continue,
+            };
+            if sender.send(command).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+pub fn play(frames: &[Frame], contraption: &Contraption) {
+    let commands = spawn_command_reader();
+    let mut delay_ms = START_DELAY_MS;
+    let mut is_paused = false;
+    let mut frame_index = 0;
+
+    while frame_index < frames.len() {
+        clear_terminal_screen();
+        print!("{}", frames[frame_index].render(contraption, true));
+        println!("[p]ause  [s]tep / enter  [+] faster  [-] slower  [q]uit   delay {delay_ms}ms");
+        io::stdout().flush().unwrap();
+
+        let command = match is_paused {
+            true => commands.recv().ok(),
+            false => {
+                thread::sleep(Duration::from_millis(delay_ms));
+                match commands.try_recv() {
+                    Ok(command) => Some(command),
+                    Err(TryRecvError::Empty) => {
+                        frame_index += 1;
+                        continue;
+                    }
+                    Err(TryRecvError::Disconnected) => None,
+                }
+            }
+        };
+
+        match command {
+            Some(Command::TogglePause) => is_paused = !is_paused,
+            Some(Command::Step) => {
+                is_paused = true;
+                frame_index += 1;
+            }
+            Some(Command::Faster) => delay_ms = (delay_ms / 2).max(MIN_DELAY_MS),
+            Some(Command::Slower) => delay_ms = (delay_ms * 2).min(MAX_DELAY_MS),
+            Some(Command::Quit) => return,
+            None => frame_index += 1,
+        }
+    }
+}
+
+// `--frames` writes every tick to FRAMES_FILE_PATH, `--play` shows them in the terminal
+pub fn main() {
+    let is_writing = std::env::args().any(|arg| arg == "--frames");
+    let is_playing = std::env::args().any(|arg| arg == "--play");
+    if !is_writing && !is_playing {
+        return;
+    }
+    println!("PLAYBACK ------------");
+    let input = fs::read_to_string(get_input_path("..\\Data\\input_1.txt"))
+        .expect("Should have been able to read the file");
+
+    let layout: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let contraption = Contraption::create(&layout);
+    let frames: Vec<Frame> =
+        BeamPlayback::create(&contraption, &[(0, 0, Direction::Right)]).collect();
+
+    if is_writing {
+        write_frames(&frames, &contraption, FRAMES_FILE_PATH).expect("frames should be writable");
+        println!("{} frames written to {FRAMES_FILE_PATH}", frames.len());
+    }
+    if is_playing {
+        play(&frames, &contraption);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{cmp::Ordering, fs};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::lagoon::{self, HexDecoder, Lagoon, PlainDecoder};
+
+const DEFAULT_SEED: u64 = 2023;
+const OUTPUT_FILE: &str = "generated_input.txt";
+const MAX_HEX_LENGTH: usize = 0xfffff;
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+pub struct PlanSize {
+    pub blocks_wide: usize,
+    pub blocks_high: usize,
+    pub loop_fill: f64, // share of the 2x2 blocks the outline runs around
+    pub max_gap_part_1: usize,
+    pub max_gap_part_2: usize, // lowered if a dig would not fit into five hex digits
+}
+
+impl PlanSize {
+    // about 700 digs per scale
+    pub fn create(scale: usize) -> PlanSize {
+        PlanSize {
+            blocks_wide: 20 * scale,
+            blocks_high: 20 * scale,
+            loop_fill: 0.6,
+            max_gap_part_1: 4,
+            max_gap_part_2: 50_000,
+        }
+    }
+}
+
+pub struct Plan {
+    pub text: String,
+    pub area_part_1: usize,
+    pub area_part_2: usize,
+}
+
+// connect, create_loop, get_loop_cells, get_direction and get_stretched_positions are kept
+// identical to 10/rust/src/generator.rs, the days do not share a library
+fn connect(connections: &mut [Vec<u8>], from: (usize, usize), to: (usize, usize)) {
+    let (direction_from, direction_to) = match (
+        to.0 as isize - from.0 as isize,
+        to.1 as isize - from.1 as isize,
+    ) {
+        (-1, 0) => (NORTH, SOUTH),
+        (1, 0) => (SOUTH, NORTH),
+        (0, -1) => (WEST, EAST),
+        (0, 1) => (EAST, WEST),
+        _ => panic!("Cells not next to each other: {from:?} {to:?}"),
+    };
+    connections[from.0][from.1] ^= direction_from;
+    connections[to.0][to.1] ^= direction_to;
+}
+
+// grows a random tree over blocks of 2x2 cells and walks around it, every cell of a block
+// in the tree ends up on one closed loop that never touches or crosses itself, returned as
+// connections per cell
+fn create_loop(
+    block_width: usize,
+    block_height: usize,
+    fill: f64,
+    rng: &mut StdRng,
+) -> Vec<Vec<u8>> {
+    let target_size = ((block_width * block_height) as f64 * fill).max(1.0) as usize;
+    let mut in_tree = vec![vec![false; block_width]; block_height];
+    let mut connections = vec![vec![0; 2 * block_width]; 2 * block_height];
+
+    let start = (
+        rng.gen_range(0..block_height),
+        rng.gen_range(0..block_width),
+    );
+    let mut tree = vec![start];
+    in_tree[start.0][start.1] = true;
+    let mut frontier = vec![];
+    loop {
+        let (y, x) = *tree.last().unwrap();
+        // every block starts as a small loop around its four cells
+        connect(&mut connections, (2 * y, 2 * x), (2 * y, 2 * x + 1));
+        connect(&mut connections, (2 * y, 2 * x + 1), (2 * y + 1, 2 * x + 1));
+        connect(&mut connections, (2 * y + 1, 2 * x + 1), (2 * y + 1, 2 * x));
+        connect(&mut connections, (2 * y + 1, 2 * x), (2 * y, 2 * x));
+        if tree.len() == target_size {
+            break;
+        }
+
+        if y > 0 {
+            frontier.push(((y, x), (y - 1, x)));
+        }
+        if y + 1 < block_height {
+            frontier.push(((y, x), (y + 1, x)));
+        }
+        if x > 0 {
+            frontier.push(((y, x), (y, x - 1)));
+        }
+        if x + 1 < block_width {
+            frontier.push(((y, x), (y, x + 1)));
+        }
+
+        let (parent, child) = loop {
+            let (parent, (y, x)) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+            if !in_tree[y][x] {
+                break (parent, (y, x));
+            }
+        };
+        in_tree[child.0][child.1] = true;
+        tree.push(child);
+
+        // merging two loops: cut the facing sides and bridge them, xor removes
+        // the cut connection and adds the bridge in one go
+        let (top_left, bottom_right) = (parent.min(child), parent.max(child));
+        let (y, x) = top_left;
+        if bottom_right.0 > y {
+            connect(&mut connections, (2 * y + 1, 2 * x), (2 * y + 1, 2 * x + 1));
+            connect(&mut connections, (2 * y + 2, 2 * x), (2 * y + 2, 2 * x + 1));
+            connect(&mut connections, (2 * y + 1, 2 * x), (2 * y + 2, 2 * x));
+            connect(
+                &mut connections,
+                (2 * y + 1, 2 * x + 1),
+                (2 * y + 2, 2 * x + 1),
+            );
+        } else {
+            connect(&mut connections, (2 * y, 2 * x + 1), (2 * y + 1, 2 * x + 1));
+            connect(&mut connections, (2 * y, 2 * x + 2), (2 * y + 1, 2 * x + 2));
+            connect(&mut connections, (2 * y, 2 * x + 1), (2 * y, 2 * x + 2));
+            connect(
+                &mut connections,
+                (2 * y + 1, 2 * x + 1),
+                (2 * y + 1, 2 * x + 2),
+            );
+        }
+    }
+    connections
+}
+
+fn get_loop_cells(connections: &[Vec<u8>], start: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut cells = vec![start];
+    let mut previous = start;
+    let mut current = start;
+    loop {
+        let (y, x) = current;
+        let neighbours = [
+            (NORTH, y.wrapping_sub(1), x),
+            (EAST, y, x + 1),
+            (SOUTH, y + 1, x),
+            (WEST, y, x.wrapping_sub(1)),
+        ];
+        let next = neighbours
+            .into_iter()
+            .filter(|(direction, _, _)| connections[y][x] & direction != 0)
+            .map(|(_, yy, xx)| (yy, xx))
+            .find(|cell| *cell != previous)
+            .unwrap();
+        if next == start {
+            return cells;
+        }
+        cells.push(next);
+        (previous, current) = (current, next);
+    }
+}
+
+fn get_direction(from: (usize, usize), to: (usize, usize)) -> u8 {
+    match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+        (Ordering::Less, _) => NORTH,
+        (Ordering::Greater, _) => SOUTH,
+        (_, Ordering::Less) => WEST,
+        _ => EAST,
+    }
+}
+
+// increasing positions with random gaps, so the loop keeps its shape but gets room inside
+fn get_stretched_positions(num_positions: usize, max_gap: usize, rng: &mut StdRng) -> Vec<usize> {
+    let mut positions = vec![rng.gen_range(0..max_gap)];
+    for _ in 1..num_positions {
+        positions.push(positions.last().unwrap() + rng.gen_range(1..=max_gap));
+    }
+    positions
+}
+
+// trench cells inside and on the outline, from shoelace and pick's theorem
+fn get_area(corners: &[(usize, usize)]) -> usize {
+    let mut twice_area = 0_isize;
+    let mut perimeter = 0;
+    for ((y, x), (next_y, next_x)) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+        twice_area += *x as isize * *next_y as isize - *next_x as isize * *y as isize;
+        perimeter += y.abs_diff(*next_y) + x.abs_diff(*next_x);
+    }
+    (twice_area.unsigned_abs() + perimeter) / 2 + 1
+}
+
+pub fn generate_plan(size: &PlanSize, rng: &mut StdRng) -> Plan {
+    let connections = create_loop(size.blocks_wide, size.blocks_high, size.loop_fill, rng);
+    let loop_start = connections
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|c| *c != 0).map(|x| (y, x)))
+        .unwrap();
+    let contour = get_loop_cells(&connections, loop_start);
+
+    // a dig runs from one turn of the outline to the next
+    let corners: Vec<(usize, usize)> = (0..contour.len())
+        .filter(|index| {
+            let previous = contour[(index + contour.len() - 1) % contour.len()];
+            let next = contour[(index + 1) % contour.len()];
+            previous.0 != next.0 && previous.1 != next.1
+        })
+        .map(|index| contour[index])
+        .collect();
+
+    let max_run = corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .map(|(from, to)| from.0.abs_diff(to.0) + from.1.abs_diff(to.1))
+        .max()
+        .unwrap();
+    let max_gap_part_2 = size.max_gap_part_2.min(MAX_HEX_LENGTH / max_run);
+
+    let stretch = |max_gap: usize, rng: &mut StdRng| -> Vec<(usize, usize)> {
+        let ys = get_stretched_positions(2 * size.blocks_high, max_gap, rng);
+        let xs = get_stretched_positions(2 * size.blocks_wide, max_gap, rng);
+        corners.iter().map(|(y, x)| (ys[*y], xs[*x])).collect()
+    };
+    let corners_part_1 = stretch(size.max_gap_part_1, rng);
+    let corners_part_2 = stretch(max_gap_part_2, rng);
+
+    let mut lines = vec![];
+    for index in 0..corners.len() {
+        let next = (index + 1) % corners.len();
+        let (from, to) = (corners_part_1[index], corners_part_1[next]);
+        let (from_2, to_2) = (corners_part_2[index], corners_part_2[next]);
+        let (direction, hex_direction) = match get_direction(from, to) {
+            EAST => ('R', 0),
+            SOUTH => ('D', 1),
+            WEST => ('L', 2),
+            _ => ('U', 3),
+        };
+        let length = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+        let length_2 = from_2.0.abs_diff(to_2.0) + from_2.1.abs_diff(to_2.1);
+        lines.push(format!(
+            "{direction} {length} (#{length_2:05x}{hex_direction})"
+        ));
+    }
+
+    Plan {
+        text: lines.join("\n"),
+        area_part_1: get_area(&corners_part_1),
+        area_part_2: get_area(&corners_part_2),
+    }
+}
+
+fn get_arg(prefix: &str, default: u64) -> u64 {
+    std::env::args()
+        .find_map(|arg| arg.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or(default)
+}
+
+// `--generate [--seed=N] [--scale=N]` writes a random closed dig plan instead of solving
+pub fn main() -> bool {
+    if !std::env::args().any(|arg| arg == "--generate") {
+        return false;
+    }
+    println!("GENERATOR ------------");
+    let seed = get_arg("--seed=", DEFAULT_SEED);
+    let scale = get_arg("--scale=", 1) as usize;
+    if scale == 0 {
+        println!("scale has to be at least 1");
+        return true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let plan = generate_plan(&PlanSize::create(scale), &mut rng);
+
+    // both readings of the plan have to pass the same checks the parts run
+    let plain = lagoon::parse_input(&plan.text, &PlainDecoder).unwrap();
+    let hex = lagoon::parse_input(&plan.text, &HexDecoder).unwrap();
+    for dig_instructions in [plain, hex] {
+        if let Err(error) = Lagoon::create(&dig_instructions).validate() {
+            panic!("generated an invalid dig plan: {error}");
+        }
+    }
+
+    fs::write(OUTPUT_FILE, &plan.text).expect("Should have been able to write the file");
+    println!("wrote {OUTPUT_FILE} with seed {seed} and scale {scale}");
+    println!(
+        "expected areas {} and {}",
+        plan.area_part_1, plan.area_part_2
+    );
+    true
+}
@@ -0,0 +1,139 @@
+pub mod instruction;
+pub mod svg;
+pub mod validation;
+
+use instruction::{Color, Instruction, InstructionError, InstructionErrorKind};
+use validation::PlanError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dig {
+    pub direction: char,
+    pub length: i128,
+    pub color: Color,
+}
+
+// how a parsed line of the dig plan is turned into a dig
+pub trait DigDecoder {
+    fn decode(&self, instruction: &Instruction) -> Result<Dig, InstructionErrorKind>;
+}
+
+// `R 6 (#70c710)` -> right 6
+pub struct PlainDecoder;
+
+impl DigDecoder for PlainDecoder {
+    fn decode(&self, instruction: &Instruction) -> Result<Dig, InstructionErrorKind> {
+        Ok(Dig {
+            direction: instruction.direction,
+            length: instruction.length,
+            color: instruction.color,
+        })
+    }
+}
+
+// `R 6 (#70c710)` -> five hex digits of length, then the direction digit
+pub struct HexDecoder;
+
+impl DigDecoder for HexDecoder {
+    fn decode(&self, instruction: &Instruction) -> Result<Dig, InstructionErrorKind> {
+        let color = instruction.color;
+        let direction = color.get_hex_direction().ok_or_else(|| {
+            let digit = char::from_digit((color.blue & 0xf) as u32, 16).unwrap();
+            InstructionErrorKind::UnknownHexDirection(digit)
+        })?;
+        let length = color.get_hex_length();
+        if length == 0 {
+            return Err(InstructionErrorKind::InvalidLength(color.to_string()));
+        }
+
+        Ok(Dig {
+            direction,
+            length,
+            color,
+        })
+    }
+}
+
+pub fn parse_input(input: &str, decoder: &impl DigDecoder) -> Result<Vec<Dig>, InstructionError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| {
+            let instruction = Instruction::parse(line, line_index)?;
+            decoder
+                .decode(&instruction)
+                .map_err(|kind| InstructionError { line_index, kind })
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct Lagoon {
+    perimeter: i128,
+    corners: Vec<(i128, i128)>, // start of every dig
+    lengths: Vec<i128>,
+    colors: Vec<Color>,
+    end: (i128, i128), // where the last dig stops, the origin for a closed loop
+}
+
+impl Lagoon {
+    pub fn create(dig_instructions: &[Dig]) -> Lagoon {
+        let mut lagoon = Lagoon {
+            perimeter: 0,
+            corners: vec![],
+            lengths: vec![],
+            colors: vec![],
+            end: (0, 0),
+        };
+
+        let (mut y, mut x) = (0, 0);
+        for instruction in dig_instructions {
+            lagoon.perimeter += instruction.length;
+            lagoon.corners.push((y, x));
+            lagoon.lengths.push(instruction.length);
+            lagoon.colors.push(instruction.color);
+
+            let (delta_y, delta_x) = match instruction.direction {
+                'U' => (-1, 0),
+                'D' => (1, 0),
+                'L' => (0, -1),
+                'R' => (0, 1),
+                _ => panic!("Unknown direction"),
+            };
+            (y, x) = (
+                y + delta_y * instruction.length,
+                x + delta_x * instruction.length,
+            );
+        }
+        lagoon.end = (y, x);
+        lagoon
+    }
+
+    pub fn get_perimeter(&self) -> i128 {
+        self.perimeter
+    }
+
+    // shoelace, twice the signed area enclosed by the centers of the border cells,
+    // positive for a clockwise loop as y grows downwards
+    fn get_double_signed_area(&self) -> i128 {
+        let num_corners = self.corners.len();
+        (0..num_corners)
+            .map(|i| {
+                let (y, x) = self.corners[i];
+                let (next_y, next_x) = self.corners[(i + 1) % num_corners];
+                x * next_y - next_x * y
+            })
+            .sum()
+    }
+
+    // pick: area = interior + border / 2 - 1
+    fn get_num_interior(&self) -> i128 {
+        (self.get_double_signed_area().abs() - self.perimeter) / 2 + 1
+    }
+
+    // interior cells plus the dug border cells, only for a closed loop that never touches itself
+    pub fn get_area(&self) -> Result<i128, PlanError> {
+        self.validate()?;
+        Ok(self.get_num_interior() + self.perimeter)
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    // first five hex digits
+    pub fn get_hex_length(&self) -> i128 {
+        ((self.red as i128) << 12) | ((self.green as i128) << 4) | (self.blue as i128 >> 4)
+    }
+
+    // last hex digit, 0 to 3 for R D L U
+    pub fn get_hex_direction(&self) -> Option<char> {
+        match self.blue & 0xf {
+            0 => Some('R'),
+            1 => Some('D'),
+            2 => Some('L'),
+            3 => Some('U'),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", hex::encode([self.red, self.green, self.blue]))
+    }
+}
+
+// one line of the dig plan, `D L (#xxxxxx)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: char,
+    pub length: i128,
+    pub color: Color,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstructionErrorKind {
+    MissingField(&'static str),
+    UnexpectedField(String),
+    UnknownDirection(String),
+    InvalidLength(String),
+    MissingOpeningParenthesis,
+    MissingClosingParenthesis,
+    MissingHash,
+    InvalidHex(String),
+    UnknownHexDirection(char),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionError {
+    pub line_index: usize,
+    pub kind: InstructionErrorKind,
+}
+
+impl fmt::Display for InstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match &self.kind {
+            InstructionErrorKind::MissingField(field) => format!("missing the {field}"),
+            InstructionErrorKind::UnexpectedField(field) => {
+                format!("unexpected {field:?} after the colour")
+            }
+            InstructionErrorKind::UnknownDirection(direction) => {
+                format!("expected a direction of U, D, L or R, found {direction:?}")
+            }
+            InstructionErrorKind::InvalidLength(length) => {
+                format!("expected a positive length, found {length:?}")
+            }
+            InstructionErrorKind::MissingOpeningParenthesis => {
+                "expected the colour to start with '('".to_string()
+            }
+            InstructionErrorKind::MissingClosingParenthesis => {
+                "expected the colour to end with ')'".to_string()
+            }
+            InstructionErrorKind::MissingHash => "expected '#' before the colour".to_string(),
+            InstructionErrorKind::InvalidHex(hexa) => {
+                format!("expected six hex digits, found {hexa:?}")
+            }
+            InstructionErrorKind::UnknownHexDirection(digit) => {
+                format!("expected a direction digit from 0 to 3, found {digit:?}")
+            }
+        };
+        write!(f, "line {}: {reason}", self.line_index)
+    }
+}
+
+impl std::error::Error for InstructionError {}
+
+impl Instruction {
+    pub fn parse(line: &str, line_index: usize) -> Result<Instruction, InstructionError> {
+        let error = |kind: InstructionErrorKind| InstructionError { line_index, kind };
+        let mut fields = line.split_whitespace();
+
+        let direction = match fields.next() {
+            Some(direction @ ("U" | "D" | "L" | "R")) => direction.chars().next().unwrap(),
+            Some(direction) => {
+                return Err(error(InstructionErrorKind::UnknownDirection(
+                    direction.to_string(),
+                )))
+            }
+            None => return Err(error(InstructionErrorKind::MissingField("direction"))),
+        };
+
+        let length = fields
+            .next()
+            .ok_or(error(InstructionErrorKind::MissingField("length")))?;
+        let length = match length.bytes().all(|b| b.is_ascii_digit()) {
+            true => length.parse::<i128>().ok().filter(|length| *length > 0),
+            false => None,
+        }
+        .ok_or(error(InstructionErrorKind::InvalidLength(
+            length.to_string(),
+        )))?;
+
+        let color = fields
+            .next()
+            .ok_or(error(InstructionErrorKind::MissingField("colour")))?;
+        let color = color
+            .strip_prefix('(')
+            .ok_or(error(InstructionErrorKind::MissingOpeningParenthesis))?;
+        let color = color
+            .strip_suffix(')')
+            .ok_or(error(InstructionErrorKind::MissingClosingParenthesis))?;
+        let hexa = color
+            .strip_prefix('#')
+            .ok_or(error(InstructionErrorKind::MissingHash))?;
+
+        let mut rgb = [0_u8; 3];
+        hex::decode_to_slice(hexa, &mut rgb)
+            .map_err(|_| error(InstructionErrorKind::InvalidHex(hexa.to_string())))?;
+
+        if let Some(field) = fields.next() {
+            return Err(error(InstructionErrorKind::UnexpectedField(
+                field.to_string(),
+            )));
+        }
+
+        Ok(Instruction {
+            direction,
+            length,
+            color: Color {
+                red: rgb[0],
+                green: rgb[1],
+                blue: rgb[2],
+            },
+        })
+    }
+}
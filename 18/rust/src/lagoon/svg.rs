@@ -0,0 +1,135 @@
+use std::fmt::Write;
+
+use super::{instruction::Color, Lagoon};
+
+const MARGIN: f64 = 10.0;
+const FILL_COLOR: &str = "#d8d8d8";
+const DECIMATED_MIN_PIXELS: f64 = 2.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SvgMode {
+    Full,                          // one line per dig
+    Decimated { min_pixels: f64 }, // digs merged until the line is at least min_pixels long
+}
+
+// a stroked piece of the trench, between two entries of the point list
+struct Piece {
+    start: usize,
+    end: usize,
+    color: Color,
+}
+
+impl Lagoon {
+    // trench corners from the start to the end of the last dig
+    fn get_points(&self) -> Vec<(i128, i128)> {
+        let mut points = self.corners.clone();
+        points.push(self.end);
+        points
+    }
+
+    // cells to pixels, so the longer side of the lagoon spans canvas_size
+    fn get_scale(points: &[(i128, i128)], canvas_size: f64) -> (f64, (i128, i128)) {
+        let min_y = points.iter().map(|(y, _)| *y).min().unwrap_or(0);
+        let max_y = points.iter().map(|(y, _)| *y).max().unwrap_or(0);
+        let min_x = points.iter().map(|(_, x)| *x).min().unwrap_or(0);
+        let max_x = points.iter().map(|(_, x)| *x).max().unwrap_or(0);
+
+        let span = (max_y - min_y).max(max_x - min_x).max(1);
+        (canvas_size / span as f64, (min_y, min_x))
+    }
+
+    fn get_pieces(&self, points: &[(i128, i128)], scale: f64, mode: SvgMode) -> Vec<Piece> {
+        let min_pixels = match mode {
+            SvgMode::Full => 0.0,
+            SvgMode::Decimated { min_pixels } => min_pixels,
+        };
+
+        let mut all_pieces = vec![];
+        let mut start = 0;
+        let mut longest_dig = 0;
+        for dig_index in 0..self.colors.len() {
+            if self.lengths[dig_index] > self.lengths[longest_dig] {
+                longest_dig = dig_index;
+            }
+
+            let end = dig_index + 1;
+            let (delta_y, delta_x) = (
+                (points[end].0 - points[start].0) as f64 * scale,
+                (points[end].1 - points[start].1) as f64 * scale,
+            );
+            let is_last = end == self.colors.len();
+            if delta_y.hypot(delta_x) >= min_pixels || is_last {
+                all_pieces.push(Piece {
+                    start,
+                    end,
+                    color: self.colors[longest_dig],
+                });
+                start = end;
+                longest_dig = end.min(self.colors.len() - 1);
+            }
+        }
+        all_pieces
+    }
+
+    // full for lagoons that fit the canvas cell by cell, decimated otherwise
+    pub fn get_svg_mode(&self, canvas_size: f64) -> SvgMode {
+        let (scale, _) = Lagoon::get_scale(&self.get_points(), canvas_size);
+        match scale >= 1.0 {
+            true => SvgMode::Full,
+            false => SvgMode::Decimated {
+                min_pixels: DECIMATED_MIN_PIXELS,
+            },
+        }
+    }
+
+    pub fn render_svg(&self, canvas_size: f64, mode: SvgMode) -> String {
+        let points = self.get_points();
+        let (scale, (min_y, min_x)) = Lagoon::get_scale(&points, canvas_size);
+        let to_pixels = |(y, x): (i128, i128)| {
+            (
+                (x - min_x) as f64 * scale + MARGIN,
+                (y - min_y) as f64 * scale + MARGIN,
+            )
+        };
+        let all_pieces = self.get_pieces(&points, scale, mode);
+        let stroke_width = match mode {
+            SvgMode::Full => scale.max(1.0),
+            SvgMode::Decimated { .. } => 1.0,
+        };
+
+        let mut svg = String::new();
+        let size = canvas_size + 2.0 * MARGIN;
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" viewBox=\"0 0 {size} {size}\">"
+        )
+        .unwrap();
+
+        // interior through the same corners as the strokes
+        let mut path = String::new();
+        for (piece_index, piece) in all_pieces.iter().enumerate() {
+            let (x, y) = to_pixels(points[piece.start]);
+            let command = if piece_index == 0 { 'M' } else { 'L' };
+            write!(path, "{command}{x:.2},{y:.2} ").unwrap();
+        }
+        writeln!(
+            svg,
+            "<path d=\"{path}Z\" fill=\"{FILL_COLOR}\" stroke=\"none\"/>"
+        )
+        .unwrap();
+
+        for piece in all_pieces.iter() {
+            let (x_1, y_1) = to_pixels(points[piece.start]);
+            let (x_2, y_2) = to_pixels(points[piece.end]);
+            writeln!(
+                svg,
+                "<line x1=\"{x_1:.2}\" y1=\"{y_1:.2}\" x2=\"{x_2:.2}\" y2=\"{y_2:.2}\" stroke=\"{}\" stroke-width=\"{stroke_width:.2}\" stroke-linecap=\"square\"/>",
+                piece.color
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
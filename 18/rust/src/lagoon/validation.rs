@@ -0,0 +1,167 @@
+use std::fmt;
+
+use super::Lagoon;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanError {
+    Empty,
+    NonPositiveLength {
+        dig_index: usize,
+    },
+    NotClosed {
+        end_offset: (i128, i128), // (y, x) of the end relative to the start
+    },
+    Crossing {
+        first_dig: usize,
+        second_dig: usize,
+        at: (i128, i128),
+    },
+    Overlap {
+        first_dig: usize,
+        second_dig: usize,
+        from: (i128, i128),
+        to: (i128, i128),
+    },
+    NoArea,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanError::Empty => write!(f, "the plan has no digs"),
+            PlanError::NonPositiveLength { dig_index } => {
+                write!(f, "dig {dig_index} has no positive length")
+            }
+            PlanError::NotClosed { end_offset } => {
+                write!(f, "the trench ends at {end_offset:?} instead of the start")
+            }
+            PlanError::Crossing {
+                first_dig,
+                second_dig,
+                at,
+            } => write!(f, "dig {first_dig} and dig {second_dig} meet at {at:?}"),
+            PlanError::Overlap {
+                first_dig,
+                second_dig,
+                from,
+                to,
+            } => write!(
+                f,
+                "dig {first_dig} and dig {second_dig} share the trench from {from:?} to {to:?}"
+            ),
+            PlanError::NoArea => write!(f, "the trench encloses nothing"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+// axis aligned trench between two corners, both included
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    min_y: i128,
+    max_y: i128,
+    min_x: i128,
+    max_x: i128,
+}
+
+impl Segment {
+    fn create(start: (i128, i128), end: (i128, i128)) -> Segment {
+        Segment {
+            min_y: start.0.min(end.0),
+            max_y: start.0.max(end.0),
+            min_x: start.1.min(end.1),
+            max_x: start.1.max(end.1),
+        }
+    }
+
+    // common cells as (from, to), a single point when the segments just touch
+    fn get_intersection(&self, other: &Segment) -> Option<((i128, i128), (i128, i128))> {
+        let (min_y, max_y) = (self.min_y.max(other.min_y), self.max_y.min(other.max_y));
+        let (min_x, max_x) = (self.min_x.max(other.min_x), self.max_x.min(other.max_x));
+        match min_y <= max_y && min_x <= max_x {
+            true => Some(((min_y, min_x), (max_y, max_x))),
+            false => None,
+        }
+    }
+}
+
+impl Lagoon {
+    fn get_segments(&self) -> Vec<Segment> {
+        let num_corners = self.corners.len();
+        (0..num_corners)
+            .map(|i| {
+                let end = match i + 1 == num_corners {
+                    true => self.end,
+                    false => self.corners[i + 1],
+                };
+                Segment::create(self.corners[i], end)
+            })
+            .collect()
+    }
+
+    fn check_segments(&self) -> Result<(), PlanError> {
+        let all_segments = self.get_segments();
+        let num_segments = all_segments.len();
+
+        for (first_dig, first) in all_segments.iter().enumerate() {
+            for (second_dig, second) in all_segments.iter().enumerate().skip(first_dig + 1) {
+                let Some((from, to)) = first.get_intersection(second) else {
+                    continue;
+                };
+                if from != to {
+                    return Err(PlanError::Overlap {
+                        first_dig,
+                        second_dig,
+                        from,
+                        to,
+                    });
+                }
+
+                // neighbouring digs share their corner
+                let is_neighbour = second_dig == first_dig + 1
+                    || (first_dig == 0 && second_dig == num_segments - 1);
+                let shared_corner = match second_dig == first_dig + 1 {
+                    true => self.corners[second_dig],
+                    false => self.corners[first_dig],
+                };
+                if !is_neighbour || from != shared_corner {
+                    return Err(PlanError::Crossing {
+                        first_dig,
+                        second_dig,
+                        at: from,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // a plan is valid when it returns to the start without touching its own trench
+    pub fn validate(&self) -> Result<Orientation, PlanError> {
+        if self.corners.is_empty() {
+            return Err(PlanError::Empty);
+        }
+        if let Some(dig_index) = self.lengths.iter().position(|length| *length <= 0) {
+            return Err(PlanError::NonPositiveLength { dig_index });
+        }
+        if self.end != (0, 0) {
+            return Err(PlanError::NotClosed {
+                end_offset: self.end,
+            });
+        }
+        self.check_segments()?;
+
+        match self.get_double_signed_area() {
+            0 => Err(PlanError::NoArea),
+            double_area if double_area > 0 => Ok(Orientation::Clockwise),
+            _ => Ok(Orientation::CounterClockwise),
+        }
+    }
+}
@@ -1,7 +1,19 @@
+mod generator;
+mod lagoon;
 mod part_1;
 mod part_2;
 
+// `--input=PATH` digs another plan, e.g. generated_input.txt
+fn get_input_path(default: &str) -> String {
+    std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--input=")?.to_string()))
+        .unwrap_or(default.to_string())
+}
+
 fn main() {
+    if generator::main() {
+        return;
+    }
     part_1::main();
     part_2::main();
 }